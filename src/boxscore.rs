@@ -0,0 +1,325 @@
+//! Batting and pitching lines, bench and bullpen, and game info.
+
+pub mod models;
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::cache;
+use crate::client::Client;
+use crate::error::Result;
+use crate::schedule::GameState;
+use models::{BoxscoreResponse, BoxscoreTeam};
+
+impl Client {
+    /// Fetches the boxscore for `game_pk`. The response does not say
+    /// whether the game is over, so `state` is the game's state as last seen
+    /// in the schedule or feed; a final boxscore is cached for good.
+    pub async fn get_boxscore(&self, game_pk: u64, state: GameState) -> Result<BoxScore> {
+        let response: BoxscoreResponse = self
+            .get_json(
+                &self.statsapi(&format!("/v1/game/{}/boxscore", game_pk)),
+                |_: &BoxscoreResponse| match state {
+                    GameState::Final => cache::FINAL_TTL,
+                    GameState::Live | GameState::Preview => cache::LIVE_TTL,
+                },
+            )
+            .await?;
+
+        Ok(BoxScore::from(&response))
+    }
+}
+
+/// A printable boxscore for both teams.
+#[derive(Debug, Clone, Serialize)]
+pub struct BoxScore {
+    pub away: TeamBox,
+    pub home: TeamBox,
+    pub info: GameInfo,
+}
+
+/// One team's half of the boxscore.
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamBox {
+    pub team: String,
+    pub batters: Vec<BattingLine>,
+    pub pitchers: Vec<PitchingLine>,
+    /// Position players who did not appear.
+    pub bench: Vec<String>,
+    /// Pitchers who did not appear.
+    pub bullpen: Vec<String>,
+    /// Substitution notes, e.g. `a-Singled for Wells in the 7th.`
+    pub notes: Vec<String>,
+}
+
+/// AB, R, H, RBI, BB, K and season AVG for one batter.
+#[derive(Debug, Clone, Serialize)]
+pub struct BattingLine {
+    pub name: String,
+    pub position: String,
+    /// Entered the game as a substitute.
+    pub substitute: bool,
+    pub at_bats: u32,
+    pub runs: u32,
+    pub hits: u32,
+    pub rbi: u32,
+    pub walks: u32,
+    pub strikeouts: u32,
+    pub avg: String,
+}
+
+/// IP, H, R, ER, BB, K and season ERA for one pitcher.
+#[derive(Debug, Clone, Serialize)]
+pub struct PitchingLine {
+    pub name: String,
+    pub innings_pitched: String,
+    pub hits: u32,
+    pub runs: u32,
+    pub earned_runs: u32,
+    pub walks: u32,
+    pub strikeouts: u32,
+    pub era: String,
+}
+
+/// Weather, attendance, umpires and the remaining game notes.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GameInfo {
+    pub weather: Option<String>,
+    pub wind: Option<String>,
+    pub attendance: Option<String>,
+    /// `(position, name)`, e.g. `("Home Plate", "Pat Hoberg")`.
+    pub umpires: Vec<(String, String)>,
+    /// Every other `label: value` note.
+    pub other: Vec<(String, String)>,
+}
+
+impl From<&BoxscoreResponse> for BoxScore {
+    fn from(response: &BoxscoreResponse) -> Self {
+        let mut info = GameInfo {
+            umpires: response
+                .officials
+                .iter()
+                .map(|o| {
+                    (
+                        o.official_type.clone().unwrap_or_default(),
+                        o.official.name(),
+                    )
+                })
+                .collect(),
+            ..GameInfo::default()
+        };
+
+        for item in &response.info {
+            let (Some(label), Some(value)) = (&item.label, &item.value) else {
+                continue;
+            };
+            let value = value.trim_end_matches('.').to_string();
+            match label.as_str() {
+                "Weather" => info.weather = Some(value),
+                "Wind" => info.wind = Some(value),
+                "Att" => info.attendance = Some(value),
+                // Already covered by `officials`.
+                "Umpires" => {}
+                _ => info.other.push((label.clone(), value)),
+            }
+        }
+
+        BoxScore {
+            away: TeamBox::from(&response.teams.away),
+            home: TeamBox::from(&response.teams.home),
+            info,
+        }
+    }
+}
+
+impl From<&BoxscoreTeam> for TeamBox {
+    fn from(team: &BoxscoreTeam) -> Self {
+        let names = |ids: &[u64]| -> Vec<String> {
+            ids.iter()
+                .filter_map(|id| team.player(*id))
+                .map(|p| p.person.name())
+                .collect()
+        };
+
+        let batters = team
+            .batters
+            .iter()
+            .filter_map(|id| team.player(*id))
+            .filter(|p| p.stats.batting.at_bats.is_some())
+            .map(|p| {
+                let game = &p.stats.batting;
+                BattingLine {
+                    name: p.person.name(),
+                    position: p
+                        .position
+                        .as_ref()
+                        .and_then(|pos| pos.abbreviation.clone())
+                        .unwrap_or_default(),
+                    substitute: p
+                        .batting_order
+                        .as_deref()
+                        .and_then(|order| order.parse::<u32>().ok())
+                        .is_some_and(|order| order % 100 != 0),
+                    at_bats: game.at_bats.unwrap_or(0),
+                    runs: game.runs.unwrap_or(0),
+                    hits: game.hits.unwrap_or(0),
+                    rbi: game.rbi.unwrap_or(0),
+                    walks: game.base_on_balls.unwrap_or(0),
+                    strikeouts: game.strike_outs.unwrap_or(0),
+                    avg: p.season_stats.batting.avg.clone().unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        let pitchers = team
+            .pitchers
+            .iter()
+            .filter_map(|id| team.player(*id))
+            .map(|p| {
+                let game = &p.stats.pitching;
+                PitchingLine {
+                    name: p.person.name(),
+                    innings_pitched: game.innings_pitched.clone().unwrap_or_default(),
+                    hits: game.hits.unwrap_or(0),
+                    runs: game.runs.unwrap_or(0),
+                    earned_runs: game.earned_runs.unwrap_or(0),
+                    walks: game.base_on_balls.unwrap_or(0),
+                    strikeouts: game.strike_outs.unwrap_or(0),
+                    era: p.season_stats.pitching.era.clone().unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        TeamBox {
+            team: team.team.label(),
+            batters,
+            pitchers,
+            bench: names(&team.bench),
+            bullpen: names(&team.bullpen),
+            notes: team
+                .note
+                .iter()
+                .filter_map(|n| match (&n.label, &n.value) {
+                    (Some(label), Some(value)) => Some(format!("{}-{}", label, value)),
+                    (None, Some(value)) => Some(value.clone()),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for BoxScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.away)?;
+        writeln!(f)?;
+        write!(f, "{}", self.home)?;
+        writeln!(f)?;
+
+        let info = &self.info;
+        if let Some(weather) = &info.weather {
+            writeln!(f, "Weather: {}", weather)?;
+        }
+        if let Some(wind) = &info.wind {
+            writeln!(f, "Wind: {}", wind)?;
+        }
+        if let Some(attendance) = &info.attendance {
+            writeln!(f, "Attendance: {}", attendance)?;
+        }
+        if !info.umpires.is_empty() {
+            let umpires: Vec<String> = info
+                .umpires
+                .iter()
+                .map(|(position, name)| format!("{}: {}", position, name))
+                .collect();
+            writeln!(f, "Umpires: {}", umpires.join(", "))?;
+        }
+        for (label, value) in &info.other {
+            writeln!(f, "{}: {}", label, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for TeamBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .batters
+            .iter()
+            .map(|b| b.name.len() + b.position.len() + 4)
+            .chain(self.pitchers.iter().map(|p| p.name.len()))
+            .chain([self.team.len() + 9])
+            .max()
+            .unwrap_or(0);
+
+        writeln!(
+            f,
+            "{:width$} {:>3} {:>3} {:>3} {:>3} {:>3} {:>3} {:>5}",
+            format!("{} Batting", self.team),
+            "AB",
+            "R",
+            "H",
+            "RBI",
+            "BB",
+            "K",
+            "AVG"
+        )?;
+        for b in &self.batters {
+            let indent = if b.substitute { "  " } else { "" };
+            writeln!(
+                f,
+                "{:width$} {:>3} {:>3} {:>3} {:>3} {:>3} {:>3} {:>5}",
+                format!("{}{}, {}", indent, b.name, b.position),
+                b.at_bats,
+                b.runs,
+                b.hits,
+                b.rbi,
+                b.walks,
+                b.strikeouts,
+                b.avg
+            )?;
+        }
+        writeln!(f)?;
+
+        writeln!(
+            f,
+            "{:width$} {:>4} {:>3} {:>3} {:>3} {:>3} {:>3} {:>5}",
+            format!("{} Pitching", self.team),
+            "IP",
+            "H",
+            "R",
+            "ER",
+            "BB",
+            "K",
+            "ERA"
+        )?;
+        for p in &self.pitchers {
+            writeln!(
+                f,
+                "{:width$} {:>4} {:>3} {:>3} {:>3} {:>3} {:>3} {:>5}",
+                p.name,
+                p.innings_pitched,
+                p.hits,
+                p.runs,
+                p.earned_runs,
+                p.walks,
+                p.strikeouts,
+                p.era
+            )?;
+        }
+
+        if !self.bench.is_empty() {
+            writeln!(f, "\nBench: {}", self.bench.join(", "))?;
+        }
+        if !self.bullpen.is_empty() {
+            writeln!(f, "Bullpen: {}", self.bullpen.join(", "))?;
+        }
+        for note in &self.notes {
+            writeln!(f, "{}", note)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+//! Typed models for the statsapi.mlb.com boxscore
+//! (`/api/v1/game/{gamePk}/boxscore`).
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::schedule::models::{Person, Team};
+
+/// Top-level body of the boxscore endpoint.
+#[derive(Debug, Deserialize)]
+pub struct BoxscoreResponse {
+    pub teams: BoxscoreTeams,
+    #[serde(default)]
+    pub officials: Vec<Official>,
+    /// Game notes such as weather, wind, attendance and duration.
+    #[serde(default)]
+    pub info: Vec<LabelValue>,
+}
+
+/// Both sides of the boxscore.
+#[derive(Debug, Deserialize)]
+pub struct BoxscoreTeams {
+    pub home: BoxscoreTeam,
+    pub away: BoxscoreTeam,
+}
+
+/// One team's players and notes.
+#[derive(Debug, Deserialize)]
+pub struct BoxscoreTeam {
+    pub team: Team,
+    /// Keyed by `ID{personId}`.
+    #[serde(default)]
+    pub players: HashMap<String, BoxscorePlayer>,
+    #[serde(default)]
+    pub batters: Vec<u64>,
+    #[serde(default)]
+    pub pitchers: Vec<u64>,
+    #[serde(default)]
+    pub bench: Vec<u64>,
+    #[serde(default)]
+    pub bullpen: Vec<u64>,
+    /// Pinch-hitting and substitution notes.
+    #[serde(default)]
+    pub note: Vec<LabelValue>,
+}
+
+impl BoxscoreTeam {
+    /// Looks up a player listed in `batters`, `pitchers`, `bench` or `bullpen`.
+    pub fn player(&self, id: u64) -> Option<&BoxscorePlayer> {
+        self.players.get(&format!("ID{}", id))
+    }
+}
+
+/// A player's game and season stats.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxscorePlayer {
+    pub person: Person,
+    pub position: Option<Position>,
+    /// `100`, `200`, ... for starters; `101`, `102`, ... for substitutes.
+    pub batting_order: Option<String>,
+    #[serde(default)]
+    pub stats: PlayerStats,
+    #[serde(default)]
+    pub season_stats: PlayerStats,
+}
+
+/// Fielding position.
+#[derive(Debug, Deserialize)]
+pub struct Position {
+    pub abbreviation: Option<String>,
+}
+
+/// Batting and pitching stats; empty objects when the player did not bat or
+/// pitch.
+#[derive(Debug, Default, Deserialize)]
+pub struct PlayerStats {
+    #[serde(default)]
+    pub batting: BattingStats,
+    #[serde(default)]
+    pub pitching: PitchingStats,
+}
+
+/// Batting counting stats.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BattingStats {
+    pub at_bats: Option<u32>,
+    pub runs: Option<u32>,
+    pub hits: Option<u32>,
+    pub rbi: Option<u32>,
+    pub base_on_balls: Option<u32>,
+    pub strike_outs: Option<u32>,
+    pub avg: Option<String>,
+}
+
+/// Pitching counting stats.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PitchingStats {
+    pub innings_pitched: Option<String>,
+    pub hits: Option<u32>,
+    pub runs: Option<u32>,
+    pub earned_runs: Option<u32>,
+    pub base_on_balls: Option<u32>,
+    pub strike_outs: Option<u32>,
+    pub era: Option<String>,
+}
+
+/// An umpire and their position.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Official {
+    pub official: Person,
+    pub official_type: Option<String>,
+}
+
+/// A `{label, value}` pair as used for game info and notes.
+#[derive(Debug, Deserialize)]
+pub struct LabelValue {
+    pub label: Option<String>,
+    pub value: Option<String>,
+}
@@ -0,0 +1,153 @@
+//! Upstream responses kept in memory and on disk, so repeated runs and
+//! `--watch` refreshes do not download what cannot have changed yet.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::client::fixture_path;
+use crate::error::{Error, Result};
+
+/// Games in progress, and boxscores, which change with every play.
+pub const LIVE_TTL: Duration = Duration::from_secs(10);
+
+/// Schedules whose games have not started, which change when one does.
+pub const PREVIEW_TTL: Duration = Duration::from_secs(60);
+
+/// Streaming site listings.
+pub const STREAMS_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Finished games, which no longer change.
+pub const FINAL_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// How long an expired response is kept in memory for revalidation before
+/// it is dropped.
+const STALE_RETENTION: Duration = Duration::from_secs(10 * 60);
+
+/// Most responses kept in memory; those expiring soonest go first.
+const MAX_MEMORY_ENTRIES: usize = 256;
+
+/// Where [`Client`](crate::Client) keeps responses.
+#[derive(Debug, Clone, Default)]
+pub enum CacheMode {
+    /// Every request goes upstream.
+    #[default]
+    Off,
+    /// Responses are reused for the life of the client.
+    Memory,
+    /// Responses are also saved in this directory and reused across runs.
+    Disk(PathBuf),
+}
+
+/// Default cache directory, e.g. `~/.cache/baseball-streams`.
+pub fn default_dir() -> Result<PathBuf> {
+    let dir = dirs::cache_dir()
+        .ok_or_else(|| Error::Config("could not determine the cache directory".into()))?;
+    Ok(dir.join("baseball-streams"))
+}
+
+/// Deletes every response saved in `dir`.
+pub fn clear(dir: &Path) -> Result<()> {
+    match fs::remove_dir_all(dir) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+/// A saved response and what is needed to revalidate it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Entry {
+    pub body: Arc<str>,
+    pub expires_at: DateTime<Utc>,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl Entry {
+    pub fn is_fresh(&self) -> bool {
+        Utc::now() < self.expires_at
+    }
+
+    /// Whether the entry expired long enough ago to be dropped from memory.
+    fn is_abandoned(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at + STALE_RETENTION < now
+    }
+}
+
+/// Responses keyed by URL.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    dir: Option<PathBuf>,
+    memory: Mutex<HashMap<String, Entry>>,
+}
+
+impl ResponseCache {
+    /// The cache for `mode`, or `None` when caching is off.
+    pub fn new(mode: CacheMode) -> Option<Self> {
+        let dir = match mode {
+            CacheMode::Off => return None,
+            CacheMode::Memory => None,
+            CacheMode::Disk(dir) => Some(dir),
+        };
+        Some(ResponseCache {
+            dir,
+            memory: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// The saved response for `url`, fresh or not. An unreadable file is
+    /// treated as missing.
+    pub fn get(&self, url: &str) -> Option<Entry> {
+        let mut memory = self.memory.lock().expect("cache lock poisoned");
+        if let Some(entry) = memory.get(url) {
+            return Some(entry.clone());
+        }
+
+        let path = fixture_path(self.dir.as_ref()?, url);
+        let entry: Entry = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+        remember(&mut memory, url, entry.clone());
+        Some(entry)
+    }
+
+    /// Saves the response for `url`. Failing to write the file only costs a
+    /// download next run, so it is reported and otherwise ignored.
+    pub fn put(&self, url: &str, entry: Entry) {
+        if let Some(dir) = &self.dir {
+            let json = serde_json::to_string(&entry).expect("cache entries always serialize");
+            let written =
+                fs::create_dir_all(dir).and_then(|_| fs::write(fixture_path(dir, url), json));
+            if let Err(err) = written {
+                eprintln!("warning: could not cache {}: {}", url, err);
+            }
+        }
+
+        let mut memory = self.memory.lock().expect("cache lock poisoned");
+        remember(&mut memory, url, entry);
+    }
+}
+
+/// Keeps `entry` in memory, first dropping responses long expired and, past
+/// [`MAX_MEMORY_ENTRIES`], those expiring soonest, so that a long-running
+/// server only holds what was asked for recently.
+fn remember(memory: &mut HashMap<String, Entry>, url: &str, entry: Entry) {
+    if !memory.contains_key(url) {
+        let now = Utc::now();
+        memory.retain(|_, entry| !entry.is_abandoned(now));
+
+        while memory.len() >= MAX_MEMORY_ENTRIES {
+            let oldest = memory
+                .iter()
+                .min_by_key(|(_, entry)| entry.expires_at)
+                .map(|(url, _)| url.clone())
+                .expect("the cache is not empty");
+            memory.remove(&oldest);
+        }
+    }
+
+    memory.insert(url.to_string(), entry);
+}
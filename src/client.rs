@@ -0,0 +1,258 @@
+//! Where upstream requests go and how they are made.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use serde::de::DeserializeOwned;
+
+use crate::cache::{CacheMode, Entry, ResponseCache};
+use crate::error::{Error, Result};
+use crate::http;
+
+/// Base URL of the MLB stats API.
+pub const STATSAPI_URL: &str = "http://statsapi.mlb.com/api";
+
+/// Base URL of the streaming site's API.
+pub const STREAMS_URL: &str = "https://streamed.su/api";
+
+/// `User-Agent` of every request unless configured otherwise.
+pub(crate) const USER_AGENT: &str = concat!("baseball-streams/", env!("CARGO_PKG_VERSION"));
+
+/// Settings for [`Client::new`].
+#[derive(Debug, Clone)]
+pub struct ClientOptions {
+    /// Base URL that statsapi paths such as `/v1/schedule` are appended to.
+    pub statsapi_url: String,
+    /// Base URL that streaming paths such as `/matches/baseball` are
+    /// appended to.
+    pub streams_url: String,
+    /// Limit on each request, from connecting to reading the whole body.
+    pub timeout: Duration,
+    pub user_agent: String,
+    /// Record responses to, or replay them from, a directory.
+    pub fixtures: Option<Fixtures>,
+    /// Reuse responses until they expire; see [`crate::cache`].
+    pub cache: CacheMode,
+}
+
+/// Saved upstream responses, one file per URL; see [`fixture_path`].
+#[derive(Debug, Clone)]
+pub enum Fixtures {
+    /// Make requests as usual and save every successful response body.
+    Record(PathBuf),
+    /// Never make requests; serve the saved bodies instead.
+    Replay(PathBuf),
+}
+
+/// File in `dir` holding the response for `url`: the URL without its scheme,
+/// with anything but letters, digits and `.,=-` replaced by `_`, e.g.
+/// `statsapi.mlb.com_api_v1_game_745890_boxscore.json`. Also names the
+/// files of the on-disk [`crate::cache`].
+pub fn fixture_path(dir: &Path, url: &str) -> PathBuf {
+    let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let name: String = url
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || ".,=-".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    dir.join(format!("{}.json", name))
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        ClientOptions {
+            statsapi_url: STATSAPI_URL.to_string(),
+            streams_url: STREAMS_URL.to_string(),
+            timeout: Duration::from_secs(30),
+            user_agent: USER_AGENT.to_string(),
+            fixtures: None,
+            cache: CacheMode::Off,
+        }
+    }
+}
+
+/// Makes every upstream request, sharing one connection pool. Cheap to
+/// clone.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    statsapi_url: String,
+    streams_url: String,
+    fixtures: Option<Fixtures>,
+    cache: Option<Arc<ResponseCache>>,
+}
+
+impl Client {
+    pub fn new(options: ClientOptions) -> Result<Client> {
+        let http = reqwest::Client::builder()
+            .timeout(options.timeout)
+            .connect_timeout(options.timeout.min(Duration::from_secs(10)))
+            .user_agent(options.user_agent)
+            .build()
+            .map_err(|err| Error::Config(format!("could not set up the HTTP client: {}", err)))?;
+
+        Ok(Client {
+            http,
+            statsapi_url: options.statsapi_url.trim_end_matches('/').to_string(),
+            streams_url: options.streams_url.trim_end_matches('/').to_string(),
+            fixtures: options.fixtures,
+            cache: ResponseCache::new(options.cache).map(Arc::new),
+        })
+    }
+
+    /// Full URL of a statsapi `path`, e.g. `/v1/schedule`.
+    pub(crate) fn statsapi(&self, path: &str) -> String {
+        format!("{}{}", self.statsapi_url, path)
+    }
+
+    /// Full URL of a streaming site `path`, e.g. `/matches/baseball`.
+    pub(crate) fn streams(&self, path: &str) -> String {
+        format!("{}{}", self.streams_url, path)
+    }
+
+    /// GETs `url` and deserializes the body into `T`, reporting the JSON
+    /// path of any field that does not match the expected shape. When
+    /// caching, the response is reused for as long as `ttl` says, given the
+    /// decoded body, and revalidated with its ETag or Last-Modified after.
+    pub(crate) async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        ttl: impl FnOnce(&T) -> Duration,
+    ) -> Result<T> {
+        if let Some(Fixtures::Replay(dir)) = &self.fixtures {
+            let path = fixture_path(dir, url);
+            let body = fs::read_to_string(&path).map_err(|err| {
+                std::io::Error::new(
+                    err.kind(),
+                    format!("no recorded response for {} at {}", url, path.display()),
+                )
+            })?;
+            return http::decode(url, &body);
+        }
+
+        let cached = self.cache.as_ref().and_then(|cache| cache.get(url));
+
+        // A fresh response is used as is; saving it again would push its
+        // expiry back on every hit.
+        if let Some(entry) = &cached
+            && entry.is_fresh()
+        {
+            self.record(url, &entry.body)?;
+            return http::decode(url, &entry.body);
+        }
+
+        let (body, etag, last_modified) = match self.fetch(url, cached.as_ref()).await? {
+            Fetched::NotModified => {
+                let entry = cached.expect("only revalidated responses are not modified");
+                (entry.body, entry.etag, entry.last_modified)
+            }
+            Fetched::Body {
+                body,
+                etag,
+                last_modified,
+            } => (body.into(), etag, last_modified),
+        };
+
+        self.record(url, &body)?;
+        let value = http::decode(url, &body)?;
+
+        if let Some(cache) = &self.cache {
+            cache.put(
+                url,
+                Entry {
+                    body,
+                    expires_at: Utc::now() + ttl(&value),
+                    etag,
+                    last_modified,
+                },
+            );
+        }
+
+        Ok(value)
+    }
+
+    /// Saves `body` as the response for `url` when recording fixtures.
+    fn record(&self, url: &str, body: &str) -> Result<()> {
+        if let Some(Fixtures::Record(dir)) = &self.fixtures {
+            fs::create_dir_all(dir)?;
+            fs::write(fixture_path(dir, url), body)?;
+        }
+        Ok(())
+    }
+
+    /// GETs `url`, asking the server to answer 304 Not Modified when
+    /// `cached` is still current.
+    async fn fetch(&self, url: &str, cached: Option<&Entry>) -> Result<Fetched> {
+        let mut request = self.http.get(url);
+        if let Some(entry) = cached {
+            if let Some(etag) = &entry.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = request.send().await.map_err(|source| Error::Network {
+            url: url.to_string(),
+            source,
+        })?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_MODIFIED && cached.is_some() {
+            return Ok(Fetched::NotModified);
+        }
+        if !status.is_success() {
+            return Err(Error::HttpStatus {
+                url: url.to_string(),
+                status,
+            });
+        }
+
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header(ETAG);
+        let last_modified = header(LAST_MODIFIED);
+
+        let body = response.text().await.map_err(|source| Error::Network {
+            url: url.to_string(),
+            source,
+        })?;
+
+        Ok(Fetched::Body {
+            body,
+            etag,
+            last_modified,
+        })
+    }
+}
+
+/// Outcome of [`Client::fetch`].
+enum Fetched {
+    NotModified,
+    Body {
+        body: String,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    },
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Client::new(ClientOptions::default()).expect("the default HTTP client always builds")
+    }
+}
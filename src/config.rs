@@ -0,0 +1,90 @@
+//! User settings persisted between runs.
+
+use std::fs;
+use std::path::PathBuf;
+
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::client::ClientOptions;
+use crate::error::{Error, Result};
+use crate::events::WebhookConfig;
+
+/// Settings stored as JSON in the user's config directory.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Teams whose games are listed first, as abbreviations, ids or names.
+    pub favorites: Vec<String>,
+    /// IANA time zone used for start times and for deciding what "today"
+    /// is, e.g. `America/Chicago`. Defaults to the system time zone.
+    pub timezone: Option<String>,
+    /// Where `notify` POSTs events, in addition to any `--webhook` URLs.
+    pub webhooks: Vec<WebhookConfig>,
+    /// Base URL for statsapi requests, e.g. an internal caching proxy.
+    /// Defaults to [`crate::client::STATSAPI_URL`].
+    pub statsapi_url: Option<String>,
+    /// Base URL for streaming site requests. Defaults to
+    /// [`crate::client::STREAMS_URL`].
+    pub streams_url: Option<String>,
+}
+
+impl Config {
+    /// Location of the config file, e.g. `~/.config/baseball-streams/config.json`.
+    pub fn path() -> Result<PathBuf> {
+        let dir = dirs::config_dir()
+            .ok_or_else(|| Error::Config("could not determine the config directory".into()))?;
+        Ok(dir.join("baseball-streams").join("config.json"))
+    }
+
+    /// Loads the config file, or the defaults when it does not exist yet.
+    pub fn load() -> Result<Config> {
+        let path = Config::path()?;
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(err) => return Err(err.into()),
+        };
+
+        serde_json::from_str(&contents)
+            .map_err(|err| Error::Config(format!("{}: {}", path.display(), err)))
+    }
+
+    /// Writes the config file, creating its directory if needed.
+    pub fn save(&self) -> Result<()> {
+        let path = Config::path()?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let json_string = serde_json::to_string_pretty(self).expect("config always serializes");
+        fs::write(path, json_string)?;
+        Ok(())
+    }
+
+    /// The configured time zone, falling back to the system one.
+    pub fn timezone(&self) -> Result<Tz> {
+        match &self.timezone {
+            Some(name) => name
+                .parse()
+                .map_err(|_| Error::Config(format!("unknown time zone `{}`", name))),
+            None => Ok(crate::dates::local_timezone()),
+        }
+    }
+
+    /// Client settings with the configured base URLs.
+    pub fn client_options(&self) -> ClientOptions {
+        let defaults = ClientOptions::default();
+        ClientOptions {
+            statsapi_url: self.statsapi_url.clone().unwrap_or(defaults.statsapi_url),
+            streams_url: self.streams_url.clone().unwrap_or(defaults.streams_url),
+            ..defaults
+        }
+    }
+
+    /// Whether `game` involves one of the favorite teams.
+    pub fn is_favorite(&self, game: &crate::Game) -> bool {
+        self.favorites.iter().any(|team| game.involves(team))
+    }
+}
@@ -0,0 +1,83 @@
+//! Parsing of the dates accepted on the command line.
+
+use std::str::FromStr;
+
+use chrono::{NaiveDate, TimeDelta, Utc};
+use chrono_tz::Tz;
+
+/// Largest day offset accepted, about a century either way.
+const MAX_OFFSET_DAYS: u64 = 36_525;
+
+/// A schedule date as typed by the user: either a calendar date or a day
+/// offset relative to today.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateSpec {
+    /// `2025-07-04`
+    Absolute(NaiveDate),
+    /// `today` (0), `yesterday` (-1), `tomorrow` (1), `+3`, `-2`, ...
+    Relative(i64),
+}
+
+impl DateSpec {
+    /// Resolves the spec against `today`. Offsets past the range of dates
+    /// stop at the first or last representable date.
+    pub fn resolve(self, today: NaiveDate) -> NaiveDate {
+        match self {
+            DateSpec::Absolute(date) => date,
+            DateSpec::Relative(days) => TimeDelta::try_days(days)
+                .and_then(|offset| today.checked_add_signed(offset))
+                .unwrap_or(if days < 0 {
+                    NaiveDate::MIN
+                } else {
+                    NaiveDate::MAX
+                }),
+        }
+    }
+}
+
+impl FromStr for DateSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "today" => return Ok(DateSpec::Relative(0)),
+            "yesterday" => return Ok(DateSpec::Relative(-1)),
+            "tomorrow" => return Ok(DateSpec::Relative(1)),
+            _ => {}
+        }
+
+        if s.starts_with(['+', '-'])
+            && let Ok(days) = s.parse::<i64>()
+        {
+            if days.unsigned_abs() > MAX_OFFSET_DAYS {
+                return Err(format!(
+                    "`{}` is too far away; offsets are limited to {} days",
+                    s, MAX_OFFSET_DAYS
+                ));
+            }
+            return Ok(DateSpec::Relative(days));
+        }
+
+        NaiveDate::parse_from_str(s, "%Y-%m-%d")
+            .map(DateSpec::Absolute)
+            .map_err(|_| {
+                format!(
+                    "`{}` is not a date; use YYYY-MM-DD, today, yesterday, tomorrow or +N/-N",
+                    s
+                )
+            })
+    }
+}
+
+/// The system time zone, or UTC when it cannot be determined.
+pub fn local_timezone() -> Tz {
+    iana_time_zone::get_timezone()
+        .ok()
+        .and_then(|name| name.parse().ok())
+        .unwrap_or(Tz::UTC)
+}
+
+/// The current calendar date in `tz`.
+pub fn today_in(tz: Tz) -> NaiveDate {
+    Utc::now().with_timezone(&tz).date_naive()
+}
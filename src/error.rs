@@ -0,0 +1,88 @@
+//! Crate-wide error type.
+
+use thiserror::Error;
+
+/// Everything that can go wrong while fetching games and streams.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The request never produced a response (DNS, connect, timeout, ...).
+    #[error("network error requesting {url}: {source}")]
+    Network {
+        url: String,
+        #[source]
+        source: reqwest::Error,
+    },
+
+    /// The server answered with a non-success status code.
+    #[error("{url} returned HTTP {status}")]
+    HttpStatus {
+        url: String,
+        status: reqwest::StatusCode,
+    },
+
+    /// The body was not valid JSON.
+    #[error("invalid JSON from {url}: {source}")]
+    Decode {
+        url: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// The body was JSON but not in the shape we expected.
+    #[error("unexpected response shape at `{path}`: {message}")]
+    Shape { path: String, message: String },
+
+    /// No streaming match corresponds to the selected game.
+    #[error("no match found for {0}")]
+    NoMatch(String),
+
+    /// The requested game is not in the listing, or none was chosen.
+    #[error("invalid game selection: {0}")]
+    InvalidSelection(String),
+
+    /// The config file could not be located or parsed.
+    #[error("config error: {0}")]
+    Config(String),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Result alias used throughout the crate.
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Process exit code for this category of error. Codes start at 3 so
+    /// they never collide with 1 (a panic) or 2 (invalid arguments, as
+    /// reported by the argument parser):
+    ///
+    /// | Code | Error |
+    /// |------|-------|
+    /// | 3 | [`Error::Network`] |
+    /// | 4 | [`Error::HttpStatus`] |
+    /// | 5 | [`Error::Decode`] |
+    /// | 6 | [`Error::Shape`] |
+    /// | 7 | [`Error::NoMatch`] |
+    /// | 8 | [`Error::InvalidSelection`] |
+    /// | 9 | [`Error::Io`] |
+    /// | 10 | [`Error::Config`] |
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Network { .. } => 3,
+            Error::HttpStatus { .. } => 4,
+            Error::Decode { .. } => 5,
+            Error::Shape { .. } => 6,
+            Error::NoMatch(_) => 7,
+            Error::InvalidSelection(_) => 8,
+            Error::Io(_) => 9,
+            Error::Config(_) => 10,
+        }
+    }
+
+    pub(crate) fn shape(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Error::Shape {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
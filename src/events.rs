@@ -0,0 +1,400 @@
+//! Detecting notable moments by diffing successive snapshots of games, and
+//! delivering them to pluggable sinks.
+
+mod webhook;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::error::{Error, Result};
+use crate::feed::models::LiveFeed;
+use crate::schedule::models::Team;
+use crate::schedule::{Game, GameState, GameTeam};
+
+pub use webhook::{SIGNATURE_HEADER, WebhookConfig, WebhookFormat, WebhookSink};
+
+/// Last regulation inning; anything later is extras.
+const REGULATION_INNINGS: u32 = 9;
+
+/// No-hitters are reported once this many innings are complete.
+const NO_HITTER_INNINGS: u32 = 6;
+
+/// The state of one game at one point in time, as far as event detection
+/// cares. Built from a schedule [`Game`] or a [`LiveFeed`], and serializable
+/// so recorded snapshots can be replayed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub game_pk: u64,
+    pub away: GameTeam,
+    pub home: GameTeam,
+    pub away_hits: u32,
+    pub home_hits: u32,
+    pub inning: Option<u32>,
+    pub inning_half: Option<String>,
+    pub is_final: bool,
+}
+
+impl From<&Game> for Snapshot {
+    fn from(game: &Game) -> Self {
+        let linescore = game.linescore.as_ref();
+        Snapshot {
+            game_pk: game.game_pk,
+            away: game.away.clone(),
+            home: game.home.clone(),
+            away_hits: linescore.map_or(0, |l| l.away.hits),
+            home_hits: linescore.map_or(0, |l| l.home.hits),
+            inning: game.inning,
+            inning_half: game.inning_half.clone(),
+            is_final: game.state == GameState::Final,
+        }
+    }
+}
+
+impl From<&LiveFeed> for Snapshot {
+    fn from(feed: &LiveFeed) -> Self {
+        let linescore = &feed.live_data.linescore;
+        let totals = linescore.teams.as_ref();
+        let team = |team: &Team, runs: Option<u32>| GameTeam {
+            score: runs.unwrap_or(0),
+            ..GameTeam::from(team)
+        };
+
+        Snapshot {
+            game_pk: feed.game_pk,
+            away: team(&feed.game_data.teams.away, totals.and_then(|t| t.away.runs)),
+            home: team(&feed.game_data.teams.home, totals.and_then(|t| t.home.runs)),
+            away_hits: totals.and_then(|t| t.away.hits).unwrap_or(0),
+            home_hits: totals.and_then(|t| t.home.hits).unwrap_or(0),
+            inning: linescore.current_inning,
+            inning_half: linescore.inning_half.clone(),
+            is_final: feed.is_final(),
+        }
+    }
+}
+
+/// Something worth telling the user about.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GameEvent {
+    pub game_pk: u64,
+    pub away: String,
+    pub home: String,
+    pub away_score: u32,
+    pub home_score: u32,
+    pub inning: Option<u32>,
+    pub inning_half: Option<String>,
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+/// What happened.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventKind {
+    /// `team` scored `runs` since the previous snapshot.
+    RunScored { team: String, runs: u32 },
+    /// `team` took the lead.
+    LeadChange { team: String },
+    /// The game went past the 9th inning.
+    ExtraInnings,
+    /// `team` has no hits through the 6th inning.
+    NoHitter { team: String },
+    /// The game ended.
+    Final,
+}
+
+impl EventKind {
+    /// The `type` this kind serializes as, e.g. `run_scored`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventKind::RunScored { .. } => "run_scored",
+            EventKind::LeadChange { .. } => "lead_change",
+            EventKind::ExtraInnings => "extra_innings",
+            EventKind::NoHitter { .. } => "no_hitter",
+            EventKind::Final => "final",
+        }
+    }
+}
+
+impl GameEvent {
+    /// Short headline, e.g. `NYY @ BOS`.
+    pub fn title(&self) -> String {
+        format!("{} @ {}", self.away, self.home)
+    }
+}
+
+/// One-line description, e.g. `NYY scored 2 runs: NYY 3, BOS 2 (Top 5)`.
+impl fmt::Display for GameEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            EventKind::RunScored { team, runs } => write!(
+                f,
+                "{} scored {} run{}",
+                team,
+                runs,
+                if *runs == 1 { "" } else { "s" }
+            )?,
+            EventKind::LeadChange { team } => write!(f, "{} takes the lead", team)?,
+            EventKind::ExtraInnings => write!(f, "Headed to extra innings")?,
+            EventKind::NoHitter { team } => write!(
+                f,
+                "{} has no hits through {} innings",
+                team, NO_HITTER_INNINGS
+            )?,
+            EventKind::Final => write!(f, "Final")?,
+        }
+
+        write!(
+            f,
+            ": {} {}, {} {}",
+            self.away, self.away_score, self.home, self.home_score
+        )?;
+        if let (Some(inning), Some(half), false) = (
+            self.inning,
+            &self.inning_half,
+            self.kind == EventKind::Final,
+        ) {
+            write!(f, " ({} {})", half, inning)?;
+        }
+        Ok(())
+    }
+}
+
+/// Remembers the last snapshot of every game and reports what changed.
+#[derive(Debug, Default)]
+pub struct EventDetector {
+    previous: HashMap<u64, Snapshot>,
+    scoring_teams: Vec<String>,
+}
+
+impl EventDetector {
+    /// Creates a detector that reports runs scored by `scoring_teams`
+    /// (abbreviations, ids or names), or by every team when empty. Other
+    /// events are reported for every game.
+    pub fn new(scoring_teams: Vec<String>) -> Self {
+        EventDetector {
+            previous: HashMap::new(),
+            scoring_teams,
+        }
+    }
+
+    /// Records `snapshots` and returns the events since the previous
+    /// snapshot of each game. The first snapshot of a game only sets the
+    /// baseline.
+    pub fn observe(&mut self, snapshots: impl IntoIterator<Item = Snapshot>) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+
+        for current in snapshots {
+            if let Some(previous) = self.previous.get(&current.game_pk) {
+                for kind in self.detect(previous, &current) {
+                    events.push(GameEvent {
+                        game_pk: current.game_pk,
+                        away: current.away.abbreviation.clone(),
+                        home: current.home.abbreviation.clone(),
+                        away_score: current.away.score,
+                        home_score: current.home.score,
+                        inning: current.inning,
+                        inning_half: current.inning_half.clone(),
+                        kind,
+                    });
+                }
+            }
+            self.previous.insert(current.game_pk, current);
+        }
+
+        events
+    }
+
+    fn detect(&self, previous: &Snapshot, current: &Snapshot) -> Vec<EventKind> {
+        let mut kinds = Vec::new();
+
+        for (before, after) in [
+            (&previous.away, &current.away),
+            (&previous.home, &current.home),
+        ] {
+            let follows = self.scoring_teams.is_empty()
+                || self.scoring_teams.iter().any(|team| after.matches(team));
+            if after.score > before.score && follows {
+                kinds.push(EventKind::RunScored {
+                    team: after.abbreviation.clone(),
+                    runs: after.score - before.score,
+                });
+            }
+        }
+
+        let leader = |s: &Snapshot| match s.away.score.cmp(&s.home.score) {
+            std::cmp::Ordering::Greater => Some(s.away.abbreviation.clone()),
+            std::cmp::Ordering::Less => Some(s.home.abbreviation.clone()),
+            std::cmp::Ordering::Equal => None,
+        };
+        if let Some(team) = leader(current)
+            && leader(previous).as_ref() != Some(&team)
+        {
+            kinds.push(EventKind::LeadChange { team });
+        }
+
+        let before = previous.inning.unwrap_or(0);
+        let after = current.inning.unwrap_or(0);
+
+        if before <= REGULATION_INNINGS && after > REGULATION_INNINGS {
+            kinds.push(EventKind::ExtraInnings);
+        }
+
+        if before <= NO_HITTER_INNINGS && after > NO_HITTER_INNINGS {
+            if current.away_hits == 0 {
+                kinds.push(EventKind::NoHitter {
+                    team: current.away.abbreviation.clone(),
+                });
+            }
+            if current.home_hits == 0 {
+                kinds.push(EventKind::NoHitter {
+                    team: current.home.abbreviation.clone(),
+                });
+            }
+        }
+
+        if current.is_final && !previous.is_final {
+            kinds.push(EventKind::Final);
+        }
+
+        kinds
+    }
+}
+
+/// Future returned by [`EventSink::send`].
+pub type SinkFuture<'a> = Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>;
+
+/// Somewhere events are delivered to.
+pub trait EventSink: Send + Sync {
+    fn send<'a>(&'a self, event: &'a GameEvent) -> SinkFuture<'a>;
+}
+
+/// Prints each event on its own line, as text or as JSON.
+#[derive(Debug, Default)]
+pub struct StdoutSink {
+    json: bool,
+}
+
+impl StdoutSink {
+    pub fn new(json: bool) -> Self {
+        StdoutSink { json }
+    }
+}
+
+impl EventSink for StdoutSink {
+    fn send<'a>(&'a self, event: &'a GameEvent) -> SinkFuture<'a> {
+        Box::pin(async move {
+            if self.json {
+                println!(
+                    "{}",
+                    serde_json::to_string(event).expect("events always serialize")
+                );
+            } else {
+                println!("[{}] {}", event.title(), event);
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Runs a command such as `notify-send` with the event title and message as
+/// its last two arguments.
+#[derive(Debug)]
+pub struct CommandSink {
+    program: String,
+    args: Vec<String>,
+}
+
+impl CommandSink {
+    /// `command` is split on whitespace into the program and its leading
+    /// arguments, e.g. `notify-send -u critical`.
+    pub fn new(command: &str) -> Self {
+        let mut words = command.split_whitespace().map(str::to_string);
+        CommandSink {
+            program: words.next().unwrap_or_default(),
+            args: words.collect(),
+        }
+    }
+}
+
+impl EventSink for CommandSink {
+    fn send<'a>(&'a self, event: &'a GameEvent) -> SinkFuture<'a> {
+        Box::pin(async move {
+            let status = tokio::process::Command::new(&self.program)
+                .args(&self.args)
+                .arg(event.title())
+                .arg(event.to_string())
+                .status()
+                .await?;
+
+            if !status.success() {
+                return Err(Error::Io(std::io::Error::other(format!(
+                    "`{}` exited with {}",
+                    self.program, status
+                ))));
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Events a sink may fall behind by before new ones are dropped for it.
+const QUEUE_CAPACITY: usize = 64;
+
+/// Delivers events to sinks in the background. Each sink gets its own task
+/// and receives events in order, so a slow or unreachable one delays neither
+/// the others nor the caller's polling.
+#[derive(Debug)]
+pub struct Dispatcher {
+    queues: Vec<mpsc::Sender<GameEvent>>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl Dispatcher {
+    /// Starts a delivery task for each sink. Must be called within a Tokio
+    /// runtime.
+    pub fn new(sinks: Vec<Box<dyn EventSink>>) -> Self {
+        let (queues, tasks) = sinks
+            .into_iter()
+            .map(|sink| {
+                let (sender, mut receiver) = mpsc::channel::<GameEvent>(QUEUE_CAPACITY);
+                let task = tokio::spawn(async move {
+                    while let Some(event) = receiver.recv().await {
+                        if let Err(err) = sink.send(&event).await {
+                            eprintln!("warning: could not deliver event: {}", err);
+                        }
+                    }
+                });
+                (sender, task)
+            })
+            .unzip();
+
+        Dispatcher { queues, tasks }
+    }
+
+    /// Queues `event` for every sink without waiting for delivery. A sink
+    /// whose queue is full misses the event, with a warning.
+    pub fn dispatch(&self, event: &GameEvent) {
+        for queue in &self.queues {
+            if queue.try_send(event.clone()).is_err() {
+                eprintln!(
+                    "warning: dropping event, a sink is {} events behind",
+                    QUEUE_CAPACITY
+                );
+            }
+        }
+    }
+
+    /// Waits for every queued event to be delivered or given up on.
+    pub async fn finish(self) {
+        drop(self.queues);
+        for task in self.tasks {
+            let _ = task.await;
+        }
+    }
+}
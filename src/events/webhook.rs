@@ -0,0 +1,223 @@
+//! Delivering events to chat bots and other HTTP endpoints.
+
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use super::{EventSink, GameEvent, SinkFuture};
+use crate::client::USER_AGENT;
+use crate::error::{Error, Result};
+
+/// Header carrying the HMAC-SHA256 of the body when a secret is configured,
+/// as `sha256=<hex>`.
+pub const SIGNATURE_HEADER: &str = "X-Baseball-Streams-Signature";
+
+/// Delay before the first retry; doubled for each one after.
+const BACKOFF: Duration = Duration::from_millis(500);
+
+/// One webhook from the `webhooks` list in the config file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhookConfig {
+    pub url: String,
+    /// Shape of the body.
+    pub format: WebhookFormat,
+    /// Text of Slack and Discord messages. `{title}`, `{message}`, `{type}`,
+    /// `{away}`, `{home}`, `{away_score}`, `{home_score}`, `{inning}` and
+    /// `{game_pk}` are replaced with the event's values.
+    pub template: Option<String>,
+    /// Key for signing the body; see [`SIGNATURE_HEADER`].
+    pub secret: Option<String>,
+    /// Event types to send, e.g. `run_scored`.
+    pub events: Vec<String>,
+    /// How many times to retry a failed delivery.
+    pub retries: u32,
+    /// Seconds to wait for each attempt before giving up on it.
+    pub timeout: u64,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        WebhookConfig {
+            url: String::new(),
+            format: WebhookFormat::Json,
+            template: None,
+            secret: None,
+            events: vec![
+                "run_scored".to_string(),
+                "lead_change".to_string(),
+                "final".to_string(),
+            ],
+            retries: 3,
+            timeout: 10,
+        }
+    }
+}
+
+/// Body sent to a webhook.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookFormat {
+    /// The event itself, as `notify --format json` prints it.
+    #[default]
+    Json,
+    /// `{"text": ...}` for Slack incoming webhooks.
+    Slack,
+    /// `{"content": ...}` for Discord webhooks.
+    Discord,
+}
+
+impl WebhookFormat {
+    fn default_template(self) -> &'static str {
+        match self {
+            WebhookFormat::Json => "{title}: {message}",
+            WebhookFormat::Slack => "*{title}* {message}",
+            WebhookFormat::Discord => "**{title}** {message}",
+        }
+    }
+}
+
+/// POSTs events to a URL, retrying with exponential backoff on network
+/// errors, 429s and 5xx responses.
+#[derive(Debug)]
+pub struct WebhookSink {
+    config: WebhookConfig,
+    client: reqwest::Client,
+}
+
+impl WebhookSink {
+    /// Fails with [`Error::Config`] unless `config.url` is an http(s) URL,
+    /// so a missing or mistyped one is reported up front instead of being
+    /// retried on every event.
+    pub fn new(config: WebhookConfig) -> Result<Self> {
+        match reqwest::Url::parse(&config.url) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => {}
+            _ => {
+                return Err(Error::Config(format!(
+                    "webhook url `{}` is not an http(s) URL",
+                    config.url
+                )));
+            }
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout))
+            .user_agent(USER_AGENT)
+            .build()
+            .map_err(|err| Error::Config(format!("could not set up the HTTP client: {}", err)))?;
+
+        Ok(WebhookSink { config, client })
+    }
+
+    /// The body this webhook would send for `event`.
+    pub fn body(&self, event: &GameEvent) -> String {
+        match self.config.format {
+            WebhookFormat::Json => serde_json::to_string(event).expect("events always serialize"),
+            WebhookFormat::Slack => serde_json::json!({ "text": self.render(event) }).to_string(),
+            WebhookFormat::Discord => {
+                serde_json::json!({ "content": self.render(event) }).to_string()
+            }
+        }
+    }
+
+    fn render(&self, event: &GameEvent) -> String {
+        let template = self
+            .config
+            .template
+            .as_deref()
+            .unwrap_or(self.config.format.default_template());
+
+        template
+            .replace("{title}", &event.title())
+            .replace("{message}", &event.to_string())
+            .replace("{type}", event.kind.name())
+            .replace("{away}", &event.away)
+            .replace("{home}", &event.home)
+            .replace("{away_score}", &event.away_score.to_string())
+            .replace("{home_score}", &event.home_score.to_string())
+            .replace(
+                "{inning}",
+                &event.inning.map(|i| i.to_string()).unwrap_or_default(),
+            )
+            .replace("{game_pk}", &event.game_pk.to_string())
+    }
+
+    fn signature(&self, body: &str) -> Option<String> {
+        let secret = self.config.secret.as_ref()?;
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+        mac.update(body.as_bytes());
+        Some(format!(
+            "sha256={}",
+            hex::encode(mac.finalize().into_bytes())
+        ))
+    }
+
+    async fn post(&self, body: &str) -> Result<()> {
+        let url = &self.config.url;
+        let mut request = self
+            .client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.to_string());
+        if let Some(signature) = self.signature(body) {
+            request = request.header(SIGNATURE_HEADER, signature);
+        }
+
+        let response = request.send().await.map_err(|source| Error::Network {
+            url: url.clone(),
+            source,
+        })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(Error::HttpStatus {
+                url: url.clone(),
+                status,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Whether a failed delivery is worth trying again.
+fn is_transient(err: &Error) -> bool {
+    match err {
+        Error::Network { .. } => true,
+        Error::HttpStatus { status, .. } => {
+            status.is_server_error() || *status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        }
+        _ => false,
+    }
+}
+
+impl EventSink for WebhookSink {
+    fn send<'a>(&'a self, event: &'a GameEvent) -> SinkFuture<'a> {
+        Box::pin(async move {
+            if !self
+                .config
+                .events
+                .iter()
+                .any(|name| name == event.kind.name())
+            {
+                return Ok(());
+            }
+
+            let body = self.body(event);
+            let mut delay = BACKOFF;
+            let mut attempt = 0;
+            loop {
+                match self.post(&body).await {
+                    Err(err) if attempt < self.config.retries && is_transient(&err) => {
+                        attempt += 1;
+                        tokio::time::sleep(delay).await;
+                        delay *= 2;
+                    }
+                    result => return result,
+                }
+            }
+        })
+    }
+}
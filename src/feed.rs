@@ -0,0 +1,282 @@
+//! The live game feed: count, runners, matchup and plays of a single game.
+
+pub mod models;
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::cache;
+use crate::client::Client;
+use crate::error::Result;
+use crate::linescore::LinescoreTable;
+use crate::schedule::GameTeam;
+use crate::schedule::models::Person;
+use models::{LiveFeed, Play};
+
+impl Client {
+    /// Fetches the live feed for `game_pk`.
+    pub async fn get_live_feed(&self, game_pk: u64) -> Result<LiveFeed> {
+        self.get_json(
+            &self.statsapi(&format!("/v1.1/game/{}/feed/live", game_pk)),
+            |feed: &LiveFeed| {
+                if feed.is_final() {
+                    cache::FINAL_TTL
+                } else {
+                    cache::LIVE_TTL
+                }
+            },
+        )
+        .await
+    }
+}
+
+/// What is happening on the field right now.
+#[derive(Debug, Clone, Serialize)]
+pub struct Situation {
+    pub game_pk: u64,
+    /// Detailed state, e.g. `In Progress`, `Final`, `Delayed`.
+    pub status: String,
+    pub away: String,
+    pub home: String,
+    pub away_score: u32,
+    pub home_score: u32,
+    pub inning: Option<u32>,
+    /// `Top`, `Middle`, `Bottom` or `End`.
+    pub inning_half: Option<String>,
+    pub balls: u32,
+    pub strikes: u32,
+    pub outs: u32,
+    pub runners: Runners,
+    pub batter: Option<String>,
+    pub pitcher: Option<String>,
+    /// Description of the most recent completed play.
+    pub last_play: Option<String>,
+}
+
+/// Who is on which base.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Runners {
+    pub first: Option<String>,
+    pub second: Option<String>,
+    pub third: Option<String>,
+}
+
+/// One completed plate appearance.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlaySummary {
+    pub at_bat_index: u32,
+    pub inning: u32,
+    /// `Top` or `Bottom`.
+    pub half: String,
+    /// Abbreviation of the team at bat.
+    pub batting_team: String,
+    pub batter: String,
+    pub pitcher: String,
+    /// Short result, e.g. `Strikeout`, `Home Run`.
+    pub event: String,
+    pub description: String,
+    pub scoring: bool,
+    pub away_score: u32,
+    pub home_score: u32,
+}
+
+/// Which plays [`LiveFeed::plays`] returns.
+#[derive(Debug, Clone, Default)]
+pub struct PlayFilter {
+    /// Only plays on which a run scored.
+    pub scoring_only: bool,
+    /// Only plays in this inning.
+    pub inning: Option<u32>,
+    /// Only plays where one of these teams is at bat, by abbreviation, id
+    /// or name.
+    pub teams: Vec<String>,
+    /// Only plays after this `atBatIndex`, for following a game.
+    pub after: Option<u32>,
+}
+
+impl Situation {
+    /// Whether the score or inning differs from `previous`.
+    pub fn progressed_since(&self, previous: &Situation) -> bool {
+        self.away_score != previous.away_score
+            || self.home_score != previous.home_score
+            || self.inning != previous.inning
+            || self.inning_half != previous.inning_half
+    }
+}
+
+impl fmt::Display for Situation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}, {} {} | {}",
+            self.away, self.away_score, self.home, self.home_score, self.status
+        )?;
+        if let (Some(inning), Some(half)) = (self.inning, &self.inning_half) {
+            write!(f, " | {} {}", half, inning)?;
+        }
+        writeln!(f)?;
+
+        writeln!(
+            f,
+            "Count: {}-{}, {} out{}",
+            self.balls,
+            self.strikes,
+            self.outs,
+            if self.outs == 1 { "" } else { "s" }
+        )?;
+
+        let bases: Vec<String> = [
+            ("1st", &self.runners.first),
+            ("2nd", &self.runners.second),
+            ("3rd", &self.runners.third),
+        ]
+        .iter()
+        .filter_map(|(base, runner)| runner.as_ref().map(|name| format!("{} {}", base, name)))
+        .collect();
+        if bases.is_empty() {
+            writeln!(f, "Bases: empty")?;
+        } else {
+            writeln!(f, "Bases: {}", bases.join(", "))?;
+        }
+
+        if let Some(batter) = &self.batter {
+            writeln!(f, "At bat: {}", batter)?;
+        }
+        if let Some(pitcher) = &self.pitcher {
+            writeln!(f, "Pitching: {}", pitcher)?;
+        }
+        if let Some(last_play) = &self.last_play {
+            writeln!(f, "Last play: {}", last_play)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LiveFeed {
+    /// Completed plate appearances in order, narrowed by `filter`.
+    pub fn plays(&self, filter: &PlayFilter) -> Vec<PlaySummary> {
+        let away = GameTeam::from(&self.game_data.teams.away);
+        let home = GameTeam::from(&self.game_data.teams.home);
+
+        self.live_data
+            .plays
+            .all_plays
+            .iter()
+            .filter(|play| play.about.is_complete)
+            .filter(|play| !filter.scoring_only || play.about.is_scoring_play)
+            .filter(|play| {
+                filter
+                    .inning
+                    .is_none_or(|inning| play.about.inning == inning)
+            })
+            .filter(|play| {
+                filter
+                    .after
+                    .is_none_or(|after| play.about.at_bat_index > after)
+            })
+            .filter(|play| {
+                let batting = if play.about.is_top_inning {
+                    &away
+                } else {
+                    &home
+                };
+                filter.teams.is_empty() || filter.teams.iter().any(|t| batting.matches(t))
+            })
+            .map(|play| PlaySummary {
+                at_bat_index: play.about.at_bat_index,
+                inning: play.about.inning,
+                half: if play.about.is_top_inning {
+                    "Top".to_string()
+                } else {
+                    "Bottom".to_string()
+                },
+                batting_team: if play.about.is_top_inning {
+                    away.abbreviation.clone()
+                } else {
+                    home.abbreviation.clone()
+                },
+                batter: play.matchup.batter.name(),
+                pitcher: play.matchup.pitcher.name(),
+                event: play.result.event.clone().unwrap_or_default(),
+                description: play.result.description.clone().unwrap_or_default(),
+                scoring: play.about.is_scoring_play,
+                away_score: play.result.away_score.unwrap_or(0),
+                home_score: play.result.home_score.unwrap_or(0),
+            })
+            .collect()
+    }
+
+    /// The plate appearance with `at_bat_index`, or the current one when
+    /// `None`.
+    pub fn plate_appearance(&self, at_bat_index: Option<u32>) -> Option<&Play> {
+        let plays = &self.live_data.plays;
+        match at_bat_index {
+            Some(index) => plays
+                .all_plays
+                .iter()
+                .find(|play| play.about.at_bat_index == index),
+            None => plays.current_play.as_ref().or(plays.all_plays.last()),
+        }
+    }
+
+    /// Whether the game is over and no further plays will appear.
+    pub fn is_final(&self) -> bool {
+        self.game_data.status.abstract_game_code.as_deref() == Some("F")
+    }
+
+    /// The inning-by-inning linescore of the game.
+    pub fn linescore_table(&self) -> LinescoreTable {
+        LinescoreTable::new(
+            &self.live_data.linescore,
+            &self.game_data.teams.away.label(),
+            &self.game_data.teams.home.label(),
+            self.is_final(),
+        )
+    }
+
+    /// Summarises the current state of the game.
+    pub fn situation(&self) -> Situation {
+        let teams = &self.game_data.teams;
+        let linescore = &self.live_data.linescore;
+        let totals = linescore.teams.as_ref();
+        let offense = linescore.offense.as_ref();
+
+        let name = |person: Option<&Person>| person.map(Person::name);
+
+        Situation {
+            game_pk: self.game_pk,
+            status: self
+                .game_data
+                .status
+                .detailed_state
+                .clone()
+                .unwrap_or_default(),
+            away: teams.away.label(),
+            home: teams.home.label(),
+            away_score: totals.and_then(|t| t.away.runs).unwrap_or(0),
+            home_score: totals.and_then(|t| t.home.runs).unwrap_or(0),
+            inning: linescore.current_inning,
+            inning_half: linescore.inning_half.clone(),
+            balls: linescore.balls.unwrap_or(0),
+            strikes: linescore.strikes.unwrap_or(0),
+            outs: linescore.outs.unwrap_or(0),
+            runners: Runners {
+                first: name(offense.and_then(|o| o.first.as_ref())),
+                second: name(offense.and_then(|o| o.second.as_ref())),
+                third: name(offense.and_then(|o| o.third.as_ref())),
+            },
+            batter: name(offense.and_then(|o| o.batter.as_ref())),
+            pitcher: name(linescore.defense.as_ref().and_then(|d| d.pitcher.as_ref())),
+            last_play: self
+                .live_data
+                .plays
+                .all_plays
+                .iter()
+                .rev()
+                .filter(|play| play.about.is_complete)
+                .find_map(|play| play.result.description.clone()),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Typed models for the statsapi.mlb.com live feed
+//! (`/api/v1.1/game/{gamePk}/feed/live`).
+
+use serde::Deserialize;
+
+use crate::schedule::models::{GameStatus, Linescore, Person, Team};
+
+/// Top-level body of the live feed.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveFeed {
+    pub game_pk: u64,
+    pub game_data: GameData,
+    pub live_data: LiveData,
+}
+
+/// Static information about the game.
+#[derive(Debug, Deserialize)]
+pub struct GameData {
+    #[serde(default)]
+    pub status: GameStatus,
+    pub teams: GameDataTeams,
+}
+
+/// Both clubs, hydrated the same way as in the schedule.
+#[derive(Debug, Deserialize)]
+pub struct GameDataTeams {
+    pub home: Team,
+    pub away: Team,
+}
+
+/// Everything that changes while the game is played.
+#[derive(Debug, Deserialize)]
+pub struct LiveData {
+    #[serde(default)]
+    pub plays: Plays,
+    #[serde(default)]
+    pub linescore: Linescore,
+}
+
+/// Plate appearances so far.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Plays {
+    #[serde(default)]
+    pub all_plays: Vec<Play>,
+    pub current_play: Option<Play>,
+    /// Indices into `all_plays`.
+    #[serde(default)]
+    pub scoring_plays: Vec<usize>,
+}
+
+/// One plate appearance.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Play {
+    #[serde(default)]
+    pub result: PlayResult,
+    pub about: PlayAbout,
+    #[serde(default)]
+    pub count: Count,
+    pub matchup: Matchup,
+    /// Pitches, pickoffs, substitutions and other events of the plate
+    /// appearance, in order.
+    #[serde(default)]
+    pub play_events: Vec<PlayEvent>,
+}
+
+/// Outcome of a plate appearance; empty while it is in progress.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayResult {
+    pub event: Option<String>,
+    pub description: Option<String>,
+    pub rbi: Option<u32>,
+    pub away_score: Option<u32>,
+    pub home_score: Option<u32>,
+}
+
+/// When a plate appearance happened.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayAbout {
+    pub at_bat_index: u32,
+    pub inning: u32,
+    pub is_top_inning: bool,
+    #[serde(default)]
+    pub is_complete: bool,
+    #[serde(default)]
+    pub is_scoring_play: bool,
+}
+
+/// Balls, strikes and outs.
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+pub struct Count {
+    #[serde(default)]
+    pub balls: u32,
+    #[serde(default)]
+    pub strikes: u32,
+    #[serde(default)]
+    pub outs: u32,
+}
+
+/// Batter and pitcher of a plate appearance.
+#[derive(Debug, Deserialize)]
+pub struct Matchup {
+    pub batter: Person,
+    pub pitcher: Person,
+}
+
+/// Something that happened during a plate appearance.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayEvent {
+    #[serde(default)]
+    pub is_pitch: bool,
+    pub pitch_number: Option<u32>,
+    #[serde(default)]
+    pub details: PlayEventDetails,
+    /// Count after the event.
+    #[serde(default)]
+    pub count: Count,
+    pub pitch_data: Option<PitchData>,
+}
+
+/// What the event was and how it was called.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayEventDetails {
+    pub description: Option<String>,
+    pub call: Option<CodeDescription>,
+    /// Pitch type, e.g. `FF` / `Four-Seam Fastball`.
+    #[serde(rename = "type")]
+    pub pitch_type: Option<CodeDescription>,
+    #[serde(default)]
+    pub is_in_play: bool,
+    #[serde(default)]
+    pub is_strike: bool,
+    #[serde(default)]
+    pub is_ball: bool,
+}
+
+/// A `{code, description}` pair.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CodeDescription {
+    pub code: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Tracking data for a pitch.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PitchData {
+    /// Release velocity in mph.
+    pub start_speed: Option<f64>,
+    pub end_speed: Option<f64>,
+    /// Top and bottom of the batter's strike zone in feet.
+    pub strike_zone_top: Option<f64>,
+    pub strike_zone_bottom: Option<f64>,
+    pub zone: Option<u32>,
+    #[serde(default)]
+    pub coordinates: PitchCoordinates,
+    #[serde(default)]
+    pub breaks: PitchBreaks,
+}
+
+/// Where the pitch crossed the plate, in feet from the catcher's view:
+/// `p_x` from the middle of the plate, `p_z` above the ground.
+#[derive(Debug, Default, Deserialize)]
+pub struct PitchCoordinates {
+    #[serde(rename = "pX")]
+    pub p_x: Option<f64>,
+    #[serde(rename = "pZ")]
+    pub p_z: Option<f64>,
+}
+
+/// Spin of the pitch.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PitchBreaks {
+    /// Revolutions per minute.
+    pub spin_rate: Option<f64>,
+    pub spin_direction: Option<f64>,
+}
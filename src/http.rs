@@ -0,0 +1,40 @@
+//! Shared helpers for decoding JSON fetched over HTTP.
+
+use serde::de::DeserializeOwned;
+
+use crate::error::{Error, Result};
+
+/// Deserializes `body`, fetched from `url`, into `T`.
+pub(crate) fn decode<T: DeserializeOwned>(url: &str, body: &str) -> Result<T> {
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
+
+    serde_path_to_error::deserialize(deserializer).map_err(|err| {
+        let path = err.path().to_string();
+        let source = err.into_inner();
+        if source.is_data() {
+            Error::shape(path, source.to_string())
+        } else {
+            Error::Decode {
+                url: url.to_string(),
+                source,
+            }
+        }
+    })
+}
+
+/// Returns the array at `value`, or a shape error naming `path`.
+pub(crate) fn expect_array<'a>(
+    value: &'a serde_json::Value,
+    path: &str,
+) -> Result<&'a Vec<serde_json::Value>> {
+    value
+        .as_array()
+        .ok_or_else(|| Error::shape(path, "expected an array"))
+}
+
+/// Returns the string at `value`, or a shape error naming `path`.
+pub(crate) fn expect_str<'a>(value: &'a serde_json::Value, path: &str) -> Result<&'a str> {
+    value
+        .as_str()
+        .ok_or_else(|| Error::shape(path, "expected a string"))
+}
@@ -0,0 +1,47 @@
+//! Find live MLB games and the streams available for them.
+//!
+//! Every upstream request goes through a [`Client`]. The [`schedule`]
+//! module talks to statsapi.mlb.com, [`feed`] follows a single game through
+//! its live feed, [`matching`] pairs a scheduled game with a streaming site
+//! match and [`sources`] lists the streams for that match. With the `server`
+//! feature, `server` serves the same data over HTTP.
+
+pub mod boxscore;
+pub mod cache;
+pub mod client;
+pub mod config;
+pub mod dates;
+pub mod error;
+pub mod events;
+pub mod feed;
+mod http;
+pub mod linescore;
+pub mod matching;
+pub mod pitches;
+pub mod schedule;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod sources;
+#[cfg(feature = "tui")]
+pub mod tui;
+
+use std::fs;
+
+pub use boxscore::BoxScore;
+pub use cache::CacheMode;
+pub use client::{Client, ClientOptions, Fixtures};
+pub use config::Config;
+pub use dates::DateSpec;
+pub use error::{Error, Result};
+pub use events::{EventDetector, EventKind, EventSink, GameEvent, Snapshot};
+pub use feed::{PlayFilter, PlaySummary, Situation};
+pub use linescore::LinescoreTable;
+pub use pitches::{Pitch, strike_zone_plot};
+pub use schedule::{Game, GameState, GameTeam, changed_games, find_game};
+
+/// Writes `map` to `filename` as pretty-printed JSON.
+pub fn write_json_to_disk(map: &serde_json::Value, filename: &str) -> Result<()> {
+    let json_string = serde_json::to_string_pretty(map).expect("a JSON value always serializes");
+    fs::write(filename, json_string)?;
+    Ok(())
+}
@@ -0,0 +1,154 @@
+//! Inning-by-inning linescore with runs, hits and errors.
+
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+use crate::schedule::models::{Linescore, LinescoreInningHalf};
+
+/// Innings shown even when the game has not reached them yet.
+const REGULATION_INNINGS: u32 = 9;
+
+/// A classic R/H/E linescore grid.
+#[derive(Debug, Clone, Serialize)]
+pub struct LinescoreTable {
+    pub innings: Vec<InningLine>,
+    pub away: TeamLine,
+    pub home: TeamLine,
+}
+
+/// Runs scored by each team in one inning.
+#[derive(Debug, Clone, Serialize)]
+pub struct InningLine {
+    pub num: u32,
+    pub away: InningRuns,
+    pub home: InningRuns,
+}
+
+/// A single cell of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InningRuns {
+    Runs(u32),
+    /// Half-inning that was not needed, shown as `X`.
+    NotPlayed,
+    /// Half-inning that has not been played yet.
+    Pending,
+}
+
+/// Totals for one team.
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamLine {
+    pub team: String,
+    pub runs: u32,
+    pub hits: u32,
+    pub errors: u32,
+}
+
+impl LinescoreTable {
+    /// Builds the grid from a hydrated linescore. `is_final` decides whether
+    /// missing half-innings are shown as `X` or left blank.
+    pub fn new(linescore: &Linescore, away: &str, home: &str, is_final: bool) -> Self {
+        let played = linescore.innings.len() as u32;
+
+        let mut innings: Vec<InningLine> = linescore
+            .innings
+            .iter()
+            .map(|inning| {
+                let missing = if is_final {
+                    InningRuns::NotPlayed
+                } else {
+                    InningRuns::Pending
+                };
+                InningLine {
+                    num: inning.num,
+                    away: inning.away.runs.map_or(missing, InningRuns::Runs),
+                    home: inning.home.runs.map_or(missing, InningRuns::Runs),
+                }
+            })
+            .collect();
+
+        if !is_final {
+            for num in played + 1..=REGULATION_INNINGS {
+                innings.push(InningLine {
+                    num,
+                    away: InningRuns::Pending,
+                    home: InningRuns::Pending,
+                });
+            }
+        }
+
+        let totals = linescore.teams.as_ref();
+        let line = |team: &str, half: Option<&LinescoreInningHalf>| TeamLine {
+            team: team.to_string(),
+            runs: half.and_then(|h| h.runs).unwrap_or(0),
+            hits: half.and_then(|h| h.hits).unwrap_or(0),
+            errors: half.and_then(|h| h.errors).unwrap_or(0),
+        };
+
+        LinescoreTable {
+            innings,
+            away: line(away, totals.map(|t| &t.away)),
+            home: line(home, totals.map(|t| &t.home)),
+        }
+    }
+}
+
+impl fmt::Display for LinescoreTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name_width = self.away.team.len().max(self.home.team.len());
+
+        write!(f, "{:name_width$} ", "")?;
+        for inning in &self.innings {
+            write!(f, "{:>3}", inning.num)?;
+        }
+        writeln!(f, " {:>3}{:>3}{:>3}", "R", "H", "E")?;
+
+        write_row(
+            f,
+            name_width,
+            &self.away,
+            self.innings.iter().map(|i| i.away),
+        )?;
+        write_row(
+            f,
+            name_width,
+            &self.home,
+            self.innings.iter().map(|i| i.home),
+        )
+    }
+}
+
+fn write_row(
+    f: &mut fmt::Formatter<'_>,
+    name_width: usize,
+    team: &TeamLine,
+    cells: impl Iterator<Item = InningRuns>,
+) -> fmt::Result {
+    write!(f, "{:name_width$} ", team.team)?;
+    for cell in cells {
+        write!(f, "{:>3}", cell.to_string())?;
+    }
+    writeln!(f, " {:>3}{:>3}{:>3}", team.runs, team.hits, team.errors)
+}
+
+impl fmt::Display for InningRuns {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InningRuns::Runs(runs) => write!(f, "{}", runs),
+            InningRuns::NotPlayed => write!(f, "X"),
+            InningRuns::Pending => Ok(()),
+        }
+    }
+}
+
+/// Runs serialize as numbers, unplayed halves as `"X"` and pending ones as
+/// `null`.
+impl Serialize for InningRuns {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            InningRuns::Runs(runs) => serializer.serialize_u32(*runs),
+            InningRuns::NotPlayed => serializer.serialize_str("X"),
+            InningRuns::Pending => serializer.serialize_none(),
+        }
+    }
+}
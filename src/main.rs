@@ -1,179 +1,819 @@
-use chrono;
-use reqwest;
-use serde_json;
+use std::collections::HashSet;
 use std::fs;
-use tokio;
-
-struct Game {
-    title: String,
-    id: String,
-}
-
-fn write_json_to_disk(
-    map: &serde_json::Value,
-    filename: &str,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let json_string = serde_json::to_string_pretty(map)?;
-    fs::write(filename, json_string)?;
-    Ok(())
-}
-
-async fn get_schedule(date_string: &str) -> Result<Vec<Game>, Box<dyn std::error::Error>> {
-    let body = reqwest::get(format!(
-        "http://statsapi.mlb.com/api/v1/schedule?sportId=1&hydrate=team,linescore&date={}",
-        date_string
-    ))
-    .await?
-    .text()
-    .await?;
-
-    let json: serde_json::Value = serde_json::from_str(&body)?;
-
-    let mut games: Vec<Game> = Vec::new();
-
-    json["dates"].as_array().unwrap().iter().for_each(|date| {
-        date["games"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .filter(|game| {
-                let status = game["status"]["abstractGameCode"].as_str().unwrap_or("");
-                status != "F" && status != "P"
-            })
-            .for_each(|game| {
-                let home_team = game["teams"]["home"]["team"]["abbreviation"]
-                    .as_str()
-                    .unwrap();
-                let away_team = game["teams"]["away"]["team"]["abbreviation"]
-                    .as_str()
-                    .unwrap();
-
-                let game_key = format!("{}_{}", home_team, away_team);
-                let home_team_score = game["teams"]["home"]["score"].as_u64().unwrap_or(0);
-                let away_team_score = game["teams"]["away"]["score"].as_u64().unwrap_or(0);
-
-                let default_map = &serde_json::Map::new();
-                let linescore = game["linescore"].as_object().unwrap_or(default_map);
-
-                let inning = linescore
-                    .get("currentInningOrdinal")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("N/A");
-                let inning_half = linescore
-                    .get("inningHalf")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Top");
-
-                let mut inning_char = "Top of";
-                if inning_half == "Bottom" {
-                    inning_char = "Bottom of";
-                }
-
-                let home_team_full_name = game["teams"]["home"]["team"]["name"].as_str().unwrap();
-                let away_team_full_name = game["teams"]["away"]["team"]["name"].as_str().unwrap();
-                let id = format!("{} vs {}", home_team_full_name, away_team_full_name);
-
-                games.push(Game {
-                    title: format!(
-                        "{} ({}) vs {} ({}) | {} {}",
-                        away_team, away_team_score, home_team, home_team_score, inning_char, inning
-                    ),
-                    id: id,
-                });
-            });
-    });
+use std::path::PathBuf;
+
+use baseball_streams::{
+    CacheMode, Client, Config, DateSpec, Error, EventDetector, EventSink, Fixtures, Game,
+    GameState, Pitch, PlayFilter, PlaySummary, Result, Situation, Snapshot, cache, changed_games,
+    dates, events, find_game, strike_zone_plot, write_json_to_disk,
+};
+use chrono_tz::Tz;
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+/// Find live MLB games and the streams available for them.
+#[derive(Parser)]
+#[command(version, about, after_long_help = EXIT_CODES)]
+struct Cli {
+    #[command(flatten)]
+    selection: Selection,
+
+    #[command(flatten)]
+    output: Output,
+
+    /// Keep refreshing the games listing or game view every SECONDS,
+    /// highlighting games whose score or inning changed
+    #[arg(
+        long,
+        global = true,
+        value_name = "SECONDS",
+        num_args = 0..=1,
+        default_missing_value = "30"
+    )]
+    watch: Option<u64>,
+
+    /// Save every upstream response in DIR, one file per URL
+    #[arg(long, global = true, value_name = "DIR", conflicts_with = "replay")]
+    record: Option<PathBuf>,
+
+    /// Make no requests; serve the responses saved by --record in DIR
+    #[arg(long, global = true, value_name = "DIR")]
+    replay: Option<PathBuf>,
+
+    /// Fetch everything from upstream instead of reusing cached responses
+    #[arg(long, global = true)]
+    no_cache: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
 
-    return Ok(games);
+#[derive(Args)]
+struct Selection {
+    /// Schedule date: YYYY-MM-DD, today, yesterday, tomorrow or +N/-N days
+    /// [default: today, falling back to yesterday]
+    #[arg(long, global = true, allow_hyphen_values = true, conflicts_with_all = ["from", "to"])]
+    date: Option<DateSpec>,
+
+    /// First date of a range; accepts the same values as --date
+    #[arg(long, global = true, allow_hyphen_values = true, requires = "to")]
+    from: Option<DateSpec>,
+
+    /// Last date of a range (inclusive)
+    #[arg(long, global = true, allow_hyphen_values = true, requires = "from")]
+    to: Option<DateSpec>,
+
+    /// Which games to list, comma separated
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "live"
+    )]
+    status: Vec<StatusArg>,
+
+    /// Only list games involving these teams, by abbreviation, id or name,
+    /// e.g. NYY,BOS
+    #[arg(long, global = true, value_delimiter = ',')]
+    team: Vec<String>,
+
+    /// Game to use, by its number in the listing or by gamePk
+    #[arg(long, global = true, value_name = "N|GAMEPK")]
+    game: Option<String>,
+
+    /// IANA time zone for start times and "today", e.g. America/Chicago
+    /// [default: the configured or system time zone]
+    #[arg(long, global = true)]
+    tz: Option<Tz>,
+
+    /// Never prompt on stdin; fail if --game is needed but missing
+    #[arg(long, global = true)]
+    non_interactive: bool,
 }
 
-async fn get_sources(id: String) -> Result<Vec<serde_json::Value>, Box<dyn std::error::Error>> {
-    println!("Getting sources for {}...", id);
+#[derive(Args)]
+struct Output {
+    /// Output format
+    #[arg(long, global = true, value_enum, default_value = "text")]
+    format: Format,
 
-    let body = reqwest::get("https://streamed.su/api/matches/baseball")
-        .await?
-        .text()
-        .await?;
+    /// Write the output to this file instead of stdout
+    #[arg(long, global = true)]
+    output: Option<PathBuf>,
+}
 
-    let json: serde_json::Value = serde_json::from_str(&body)?;
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    /// A single pretty-printed JSON document
+    Json,
+    /// One compact JSON document per line; lists emit one line per item
+    Ndjson,
+}
 
-    let matches = json.as_array().unwrap();
+impl Output {
+    /// Writes `value` in the selected format, using `text` to render the
+    /// human readable form.
+    fn emit<T: Serialize>(&self, value: &T, text: impl FnOnce() -> String) -> Result<()> {
+        let json = serde_json::to_value(value).expect("output types always serialize");
+
+        match self.format {
+            Format::Text => self.write(text()),
+            Format::Json => match &self.output {
+                Some(path) => write_json_to_disk(&json, &path.to_string_lossy()),
+                None => {
+                    println!("{:#}", json);
+                    Ok(())
+                }
+            },
+            Format::Ndjson => {
+                let lines = match json {
+                    serde_json::Value::Array(items) => items,
+                    other => vec![other],
+                };
+
+                let mut contents = String::new();
+                for line in lines {
+                    contents.push_str(&line.to_string());
+                    contents.push('\n');
+                }
+                self.write(contents)
+            }
+        }
+    }
 
-    for m in matches {
-        let match_title = m["title"].as_str().unwrap();
-        if match_title == id {
-            let m_sources = m["sources"].as_array().unwrap().clone();
-            return Ok(m_sources);
+    /// Clears the terminal before a refresh in `--watch` mode.
+    fn clear_screen(&self) {
+        if self.format == Format::Text && self.output.is_none() {
+            print!("\x1b[2J\x1b[H");
         }
     }
 
-    return Ok(Vec::new());
-}
+    fn write(&self, contents: String) -> Result<()> {
+        match &self.output {
+            Some(path) => fs::write(path, contents)?,
+            None => print!("{}", contents),
+        }
+        Ok(())
+    }
 
-async fn get_streams(sources: Vec<serde_json::Value>) -> Result<(), Box<dyn std::error::Error>> {
-    println!("");
-    println!("Streams: ");
-    println!("");
+    /// Prints progress and prompts where they do not mix with the output.
+    fn note(&self, message: &str) {
+        if self.format == Format::Text && self.output.is_none() {
+            println!("{}", message);
+        } else {
+            eprintln!("{}", message);
+        }
+    }
+}
 
-    for source in sources {
-        let source_id = source["id"].as_str().unwrap();
-        let source_type = source["source"].as_str().unwrap();
+/// What the `pitches` command emits.
+#[derive(Serialize)]
+struct PitchesReport {
+    at_bat_index: u32,
+    inning: u32,
+    half: &'static str,
+    batter: String,
+    pitcher: String,
+    pitches: Vec<Pitch>,
+}
 
-        let url = format!(
-            "https://streamed.su/api/stream/{}/{}",
-            source_type, source_id
-        );
+/// What the `sources` command emits.
+#[derive(Serialize)]
+struct SourcesReport<'a> {
+    game: &'a Game,
+    sources: Vec<serde_json::Value>,
+    streams: Vec<String>,
+}
 
-        let body = reqwest::get(url).await?.text().await?;
+#[derive(Clone, Copy, ValueEnum)]
+enum StatusArg {
+    Live,
+    Preview,
+    Final,
+    All,
+}
 
-        let json: serde_json::Value = serde_json::from_str(&body)?;
+impl Selection {
+    /// --tz, else the configured time zone, else the system one.
+    fn timezone(&self) -> Result<Tz> {
+        match self.tz {
+            Some(tz) => Ok(tz),
+            None => Config::load()?.timezone(),
+        }
+    }
 
-        let streams = json.as_array().unwrap();
-        for stream in streams {
-            println!("{}", stream["embedUrl"].as_str().unwrap());
+    fn states(&self) -> Vec<GameState> {
+        let mut states = Vec::new();
+        for status in &self.status {
+            match status {
+                StatusArg::Live => states.push(GameState::Live),
+                StatusArg::Preview => states.push(GameState::Preview),
+                StatusArg::Final => states.push(GameState::Final),
+                StatusArg::All => states.extend(GameState::ALL),
+            }
         }
+        states
     }
+}
 
-    return Ok(());
+#[derive(Subcommand)]
+enum Command {
+    /// List the games being played
+    Games,
+    /// Pick a game and print its stream URLs (the default)
+    Sources,
+    /// Pick a game and print its score line
+    Score,
+    /// Pick a game and show count, runners, matchup and the last play
+    Game,
+    /// Pick a game and print its inning-by-inning linescore
+    Linescore,
+    /// Pick a game and print batting and pitching lines for both teams
+    Boxscore,
+    /// Pick a game and print its play-by-play; --team limits it to that
+    /// team's plate appearances
+    Plays {
+        /// Only plays on which a run scored
+        #[arg(long)]
+        scoring_only: bool,
+
+        /// Only plays in this inning
+        #[arg(long)]
+        inning: Option<u32>,
+
+        /// Keep polling and print new plays as they happen, every SECONDS
+        #[arg(long, value_name = "SECONDS", num_args = 0..=1, default_missing_value = "15")]
+        follow: Option<u64>,
+    },
+    /// Pick a game and show each pitch of a plate appearance with a strike
+    /// zone plot
+    Pitches {
+        /// atBatIndex of the plate appearance, as shown by `plays --format json`
+        /// [default: the current one]
+        #[arg(long, value_name = "INDEX")]
+        at_bat: Option<u32>,
+    },
+    /// Full-screen view with the game list, linescore, box and plays;
+    /// refreshes every --watch seconds (default 30)
+    #[cfg(feature = "tui")]
+    Tui,
+    /// Serve schedule and game data as JSON over HTTP: /games?date=,
+    /// /games/{gamePk} and /games/{gamePk}/linescore, plus live updates
+    /// from /games/{gamePk}/events (SSE) and /games/{gamePk}/ws
+    #[cfg(feature = "server")]
+    Serve {
+        /// Address to listen on
+        #[arg(long, value_name = "ADDR", default_value = "127.0.0.1:8080")]
+        listen: std::net::SocketAddr,
+    },
+    /// Watch the listed games and report runs scored by favorite teams
+    /// (or every team when there are none), lead changes, extra innings,
+    /// no-hitters through the 6th and finals; polls every --watch seconds
+    /// (default 30)
+    Notify {
+        /// Also run this command with each event's title and message as
+        /// arguments, e.g. notify-send
+        #[arg(long, value_name = "COMMAND")]
+        notify_command: Option<String>,
+
+        /// Also POST run, lead change and final events as JSON to this URL,
+        /// like the `webhooks` in the config file; may be repeated
+        #[arg(long, value_name = "URL")]
+        webhook: Vec<String>,
+    },
+    /// Manage the teams whose games are listed first
+    #[command(subcommand)]
+    Favorites(FavoritesCommand),
+    /// Manage the cache of upstream responses
+    #[command(subcommand)]
+    Cache(CacheCommand),
 }
 
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Delete every cached response
+    Clear,
+}
+
+#[derive(Subcommand)]
+enum FavoritesCommand {
+    /// Print the favorite teams
+    List,
+    /// Add teams by abbreviation, id or name
+    Add {
+        #[arg(required = true)]
+        teams: Vec<String>,
+    },
+    /// Remove teams from the favorites
+    Remove {
+        #[arg(required = true)]
+        teams: Vec<String>,
+    },
+}
+
+/// Shown at the end of `--help`; see [`Error::exit_code`].
+const EXIT_CODES: &str = "\
+Exit codes:
+  0   success
+  2   invalid arguments
+  3   network error
+  4   HTTP error status
+  5   invalid JSON
+  6   unexpected response shape
+  7   no stream match for the game
+  8   invalid game selection
+  9   I/O error
+  10  config error";
+
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let date = chrono::Local::now().format("%Y-%m-%d").to_string();
-    let mut games = get_schedule(&date).await?;
+async fn main() {
+    let cli = Cli::parse();
 
-    if games.len() == 0 {
-        let date = (chrono::Local::now() - chrono::Duration::days(1))
-            .format("%Y-%m-%d")
-            .to_string();
-        games = get_schedule(&date).await?;
+    if let Err(err) = run(cli).await {
+        eprintln!("error: {}", err);
+        std::process::exit(err.exit_code());
     }
+}
 
-    println!("\nAvailable games:");
-    for (i, game) in games.iter().enumerate() {
-        println!("{}. {}", i + 1, game.title);
+async fn run(cli: Cli) -> Result<()> {
+    let command = cli.command.unwrap_or(Command::Sources);
+
+    if let Command::Favorites(command) = command {
+        return manage_favorites(command);
     }
 
-    println!("\nSelect a game number:");
-    let mut input = String::new();
-    std::io::stdin()
-        .read_line(&mut input)
-        .expect("Failed to read line");
+    if let Command::Cache(CacheCommand::Clear) = command {
+        let dir = cache::default_dir()?;
+        cache::clear(&dir)?;
+        println!("cleared {}", dir.display());
+        return Ok(());
+    }
+
+    let mut options = Config::load()?.client_options();
+    options.fixtures = match (&cli.record, &cli.replay) {
+        (Some(dir), _) => Some(Fixtures::Record(dir.clone())),
+        (_, Some(dir)) => Some(Fixtures::Replay(dir.clone())),
+        _ => None,
+    };
+    if !cli.no_cache {
+        options.cache = match &command {
+            // The server polls live games far too often to save each
+            // response to disk, and is not restarted often enough to gain.
+            #[cfg(feature = "server")]
+            Command::Serve { .. } => CacheMode::Memory,
+            _ => CacheMode::Disk(cache::default_dir()?),
+        };
+    }
+    let client = Client::new(options)?;
+
+    #[cfg(feature = "tui")]
+    if let Command::Tui = command {
+        let tz = cli.selection.timezone()?;
+        let today = dates::today_in(tz);
+        return baseball_streams::tui::run(baseball_streams::tui::TuiOptions {
+            client,
+            date: cli.selection.date.map_or(today, |date| date.resolve(today)),
+            tz,
+            teams: cli.selection.team.clone(),
+            refresh: std::time::Duration::from_secs(cli.watch.unwrap_or(30)),
+        })
+        .await;
+    }
+
+    #[cfg(feature = "server")]
+    if let Command::Serve { listen } = command {
+        eprintln!("listening on http://{}", listen);
+        return baseball_streams::server::serve(baseball_streams::server::ServeOptions {
+            addr: listen,
+            tz: cli.selection.timezone()?,
+            client,
+        })
+        .await;
+    }
+
+    if let Command::Notify {
+        notify_command,
+        webhook,
+    } = command
+    {
+        return notify(
+            &client,
+            &cli.selection,
+            &cli.output,
+            cli.watch,
+            notify_command,
+            webhook,
+        )
+        .await;
+    }
+
+    let games = load_games(&client, &cli.selection, &cli.selection.states()).await?;
+
+    let output = &cli.output;
+
+    match command {
+        Command::Favorites(_) | Command::Cache(_) => {
+            unreachable!("handled before loading games")
+        }
+        #[cfg(feature = "tui")]
+        Command::Tui => unreachable!("handled before loading games"),
+        #[cfg(feature = "server")]
+        Command::Serve { .. } => unreachable!("handled before loading games"),
+        Command::Notify { .. } => unreachable!("handled before loading games"),
+        Command::Games => {
+            let Some(seconds) = cli.watch else {
+                return output.emit(&games, || games_text(&games, &HashSet::new()));
+            };
+
+            let mut games = games;
+            let mut changed = HashSet::new();
+            loop {
+                output.clear_screen();
+                output.emit(&games, || games_text(&games, &changed))?;
+                sleep(seconds).await;
+
+                match load_games(&client, &cli.selection, &cli.selection.states()).await {
+                    Ok(current) => {
+                        changed = changed_games(&games, &current);
+                        games = current;
+                    }
+                    Err(err) => eprintln!("warning: refresh failed: {}", err),
+                }
+            }
+        }
+        Command::Score => {
+            let game = select_game(&games, &cli.selection, output)?;
+            output.emit(game, || format!("{}\n", game.title))
+        }
+        Command::Game => {
+            let game = select_game(&games, &cli.selection, output)?;
+            let mut previous: Option<Situation> = None;
+
+            loop {
+                let situation = match client.get_live_feed(game.game_pk).await {
+                    Ok(feed) => feed.situation(),
+                    Err(err) if previous.is_some() => {
+                        eprintln!("warning: refresh failed: {}", err);
+                        sleep(cli.watch.unwrap_or_default()).await;
+                        continue;
+                    }
+                    Err(err) => return Err(err),
+                };
+                let changed = previous
+                    .as_ref()
+                    .is_some_and(|p| situation.progressed_since(p));
+
+                output.clear_screen();
+                output.emit(&situation, || situation_text(&situation, changed))?;
+
+                let Some(seconds) = cli.watch else {
+                    return Ok(());
+                };
+                previous = Some(situation);
+                sleep(seconds).await;
+            }
+        }
+        Command::Linescore => {
+            let game = select_game(&games, &cli.selection, output)?;
+            output.emit(&game.linescore, || match &game.linescore {
+                Some(linescore) => format!("{}\n{}", game.title, linescore),
+                None => format!("No linescore yet for {}\n", game.title),
+            })
+        }
+        Command::Boxscore => {
+            let game = select_game(&games, &cli.selection, output)?;
+            let boxscore = client.get_boxscore(game.game_pk, game.state).await?;
+            output.emit(&boxscore, || format!("{}\n\n{}", game.title, boxscore))
+        }
+        Command::Plays {
+            scoring_only,
+            inning,
+            follow,
+        } => {
+            let game = select_game(&games, &cli.selection, output)?;
+            let mut filter = PlayFilter {
+                scoring_only,
+                inning,
+                teams: cli.selection.team.clone(),
+                after: None,
+            };
+
+            let mut first = true;
+            loop {
+                let feed = match client.get_live_feed(game.game_pk).await {
+                    Ok(feed) => feed,
+                    Err(err) if !first => {
+                        eprintln!("warning: refresh failed: {}", err);
+                        sleep(follow.unwrap_or_default()).await;
+                        continue;
+                    }
+                    Err(err) => return Err(err),
+                };
+                let plays = feed.plays(&filter);
+
+                if first || !plays.is_empty() {
+                    output.emit(&plays, || plays_text(game, &plays))?;
+                }
+                if let Some(last) = plays.last() {
+                    filter.after = Some(last.at_bat_index);
+                }
+                first = false;
 
-    let game_number: i32 = match input.trim().parse::<i32>() {
-        Ok(num) if num > 0 && num <= games.len() as i32 => num - 1,
+                match follow {
+                    Some(seconds) if !feed.is_final() => sleep(seconds).await,
+                    _ => return Ok(()),
+                }
+            }
+        }
+        Command::Pitches { at_bat } => {
+            let game = select_game(&games, &cli.selection, output)?;
+            let feed = client.get_live_feed(game.game_pk).await?;
+            let play = feed.plate_appearance(at_bat).ok_or_else(|| {
+                Error::InvalidSelection(match at_bat {
+                    Some(index) => format!("no plate appearance with atBatIndex {}", index),
+                    None => "the game has no plate appearances yet".to_string(),
+                })
+            })?;
+
+            let report = PitchesReport {
+                at_bat_index: play.about.at_bat_index,
+                inning: play.about.inning,
+                half: if play.about.is_top_inning {
+                    "Top"
+                } else {
+                    "Bottom"
+                },
+                batter: play.matchup.batter.name(),
+                pitcher: play.matchup.pitcher.name(),
+                pitches: play.pitches(),
+            };
+            output.emit(&report, || pitches_text(&report))
+        }
+        Command::Sources => {
+            let game = select_game(&games, &cli.selection, output)?;
+
+            output.note(&format!("\nSelected game: {}\n", game.title));
+
+            output.note(&format!("Getting sources for {}...", game.id));
+            let sources = client.get_sources(game).await?;
+            let streams = client.get_streams(&sources).await?;
+
+            let report = SourcesReport {
+                game,
+                sources,
+                streams,
+            };
+            output.emit(&report, || {
+                let mut text = String::from("\nStreams: \n\n");
+                for stream in &report.streams {
+                    text.push_str(stream);
+                    text.push('\n');
+                }
+                text
+            })
+        }
+    }
+}
+
+async fn load_games(
+    client: &Client,
+    selection: &Selection,
+    states: &[GameState],
+) -> Result<Vec<Game>> {
+    let config = Config::load()?;
+    let tz = selection.timezone()?;
+    let today = dates::today_in(tz);
+
+    let mut games = match (selection.date, selection.from, selection.to) {
+        (_, Some(from), Some(to)) => {
+            let (from, to) = (from.resolve(today), to.resolve(today));
+            if from > to {
+                Cli::command()
+                    .error(
+                        clap::error::ErrorKind::ValueValidation,
+                        format!("--from {} is after --to {}", from, to),
+                    )
+                    .exit();
+            }
+
+            client
+                .get_schedule_range(&from.to_string(), &to.to_string(), states, tz)
+                .await?
+        }
+        (Some(date), _, _) => {
+            client
+                .get_schedule(&date.resolve(today).to_string(), states, tz)
+                .await?
+        }
         _ => {
-            println!("Invalid selection");
-            return Ok(());
+            let mut games = client.get_schedule(&today.to_string(), states, tz).await?;
+
+            if games.is_empty() {
+                let yesterday = today - chrono::Duration::days(1);
+                games = client
+                    .get_schedule(&yesterday.to_string(), states, tz)
+                    .await?;
+            }
+
+            games
         }
     };
 
-    println!("\nSelected game: {}", games[game_number as usize].title);
-    println!("");
+    if !selection.team.is_empty() {
+        games.retain(|game| selection.team.iter().any(|team| game.involves(team)));
+    }
+
+    games.sort_by_key(|game| (game.date.clone(), !config.is_favorite(game)));
+
+    Ok(games)
+}
+
+/// Polls the schedule and sends every detected event to stdout and the
+/// requested sinks. Games in every state are watched so finals are seen.
+async fn notify(
+    client: &Client,
+    selection: &Selection,
+    output: &Output,
+    watch: Option<u64>,
+    command: Option<String>,
+    webhooks: Vec<String>,
+) -> Result<()> {
+    let config = Config::load()?;
+    let seconds = watch.unwrap_or(30);
+
+    let mut sinks: Vec<Box<dyn EventSink>> = vec![Box::new(events::StdoutSink::new(
+        output.format != Format::Text,
+    ))];
+    if let Some(command) = command {
+        sinks.push(Box::new(events::CommandSink::new(&command)));
+    }
+    let webhooks = webhooks.into_iter().map(|url| events::WebhookConfig {
+        url,
+        ..Default::default()
+    });
+    for webhook in config.webhooks.into_iter().chain(webhooks) {
+        sinks.push(Box::new(events::WebhookSink::new(webhook)?));
+    }
+
+    let dispatcher = events::Dispatcher::new(sinks);
+    let mut detector = EventDetector::new(config.favorites);
+    loop {
+        match load_games(client, selection, &GameState::ALL).await {
+            Ok(games) => {
+                for event in detector.observe(games.iter().map(Snapshot::from)) {
+                    dispatcher.dispatch(&event);
+                }
+            }
+            Err(err) => eprintln!("warning: refresh failed: {}", err),
+        }
+        sleep(seconds).await;
+    }
+}
+
+fn manage_favorites(command: FavoritesCommand) -> Result<()> {
+    let mut config = Config::load()?;
+
+    match command {
+        FavoritesCommand::List => {
+            for team in &config.favorites {
+                println!("{}", team);
+            }
+            return Ok(());
+        }
+        FavoritesCommand::Add { teams } => {
+            for team in teams {
+                if !config
+                    .favorites
+                    .iter()
+                    .any(|t| t.eq_ignore_ascii_case(&team))
+                {
+                    config.favorites.push(team);
+                }
+            }
+        }
+        FavoritesCommand::Remove { teams } => {
+            config
+                .favorites
+                .retain(|t| !teams.iter().any(|team| team.eq_ignore_ascii_case(t)));
+        }
+    }
+
+    config.save()
+}
+
+async fn sleep(seconds: u64) {
+    tokio::time::sleep(std::time::Duration::from_secs(seconds)).await
+}
+
+/// Wraps `line` in ANSI bold yellow.
+fn highlight(line: &str) -> String {
+    format!("\x1b[1;33m{}\x1b[0m", line)
+}
+
+/// The numbered games listing; games in `changed` are highlighted.
+fn games_text(games: &[Game], changed: &HashSet<u64>) -> String {
+    let multiple_dates = games.iter().any(|game| game.date != games[0].date);
+
+    let mut text = String::from("\nAvailable games:\n");
+    for (i, game) in games.iter().enumerate() {
+        if multiple_dates && (i == 0 || games[i - 1].date != game.date) {
+            text.push_str(&format!("\n{}\n", game.date));
+        }
+
+        let line = format!("{}. {}", i + 1, game.title);
+        if changed.contains(&game.game_pk) {
+            text.push_str(&highlight(&line));
+        } else {
+            text.push_str(&line);
+        }
+        text.push('\n');
+    }
+    text
+}
+
+fn situation_text(situation: &Situation, changed: bool) -> String {
+    let text = situation.to_string();
+    if !changed {
+        return text;
+    }
+
+    match text.split_once('\n') {
+        Some((headline, rest)) => format!("{}\n{}", highlight(headline), rest),
+        None => highlight(&text),
+    }
+}
+
+fn plays_text(game: &Game, plays: &[PlaySummary]) -> String {
+    let mut text = String::new();
+    for play in plays {
+        text.push_str(&format!(
+            "{:<6} {:>2} | {} vs {} | {} | {} {}, {} {}\n",
+            play.half,
+            play.inning,
+            play.batter,
+            play.pitcher,
+            play.event,
+            game.away.abbreviation,
+            play.away_score,
+            game.home.abbreviation,
+            play.home_score
+        ));
+        text.push_str(&format!("            {}\n", play.description));
+    }
+    text
+}
 
-    let game_id = games[game_number as usize].id.clone();
+fn pitches_text(report: &PitchesReport) -> String {
+    let mut text = format!(
+        "{} vs {} | {} {}\n\n",
+        report.batter, report.pitcher, report.half, report.inning
+    );
+
+    text.push_str(&format!(
+        "{:>2}  {:<22} {:>5} {:>5} {:>4}  {:<5} {}\n",
+        "#", "Type", "MPH", "Spin", "Zone", "Count", "Call"
+    ));
+    for pitch in &report.pitches {
+        let optional = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+        text.push_str(&format!(
+            "{:>2}  {:<22} {:>5} {:>5} {:>4}  {:<5} {}\n",
+            pitch.number,
+            optional(pitch.pitch_type.clone()),
+            optional(pitch.velocity.map(|v| format!("{:.1}", v))),
+            optional(pitch.spin_rate.map(|s| format!("{:.0}", s))),
+            optional(pitch.zone.map(|z| z.to_string())),
+            format!("{}-{}", pitch.balls, pitch.strikes),
+            pitch.call
+        ));
+    }
 
-    let sources = get_sources(game_id).await?;
-    get_streams(sources).await?;
+    text.push('\n');
+    text.push_str(&strike_zone_plot(&report.pitches));
+    text
+}
 
-    return Ok(());
+fn select_game<'a>(games: &'a [Game], selection: &Selection, output: &Output) -> Result<&'a Game> {
+    if let Some(selector) = &selection.game {
+        return find_game(games, selector)
+            .ok_or_else(|| Error::InvalidSelection(format!("no game matches {}", selector)));
+    }
+
+    if selection.non_interactive {
+        return Err(Error::InvalidSelection(
+            "--game is required with --non-interactive".to_string(),
+        ));
+    }
+
+    output.note(games_text(games, &HashSet::new()).trim_end());
+
+    output.note("\nSelect a game number:");
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+
+    match input.trim().parse::<usize>() {
+        Ok(num) if num > 0 && num <= games.len() => Ok(&games[num - 1]),
+        _ => Err(Error::InvalidSelection(input.trim().to_string())),
+    }
 }
@@ -0,0 +1,52 @@
+//! Matching schedule games against the streaming site's match list.
+
+use crate::error::Result;
+use crate::http;
+use crate::schedule::Game;
+
+/// How far a match's start may be from a doubleheader game's before it is
+/// taken to be the other game.
+const MAX_START_DIFFERENCE_MS: i64 = 2 * 60 * 60 * 1000;
+
+/// Finds the match for `game`.
+///
+/// The site knows nothing of gamePks, so matches are paired by title. A
+/// single game takes its lone match whatever time the site lists, since
+/// delays and placeholder times are common. Otherwise the match nearest the
+/// game's start wins; for a doubleheader it must also be within two hours,
+/// so a lone listing for Game 1 is not taken for Game 2. Only when the site
+/// lists no start times does the game number pick among the titles.
+pub fn find_match<'a>(
+    matches: &'a [serde_json::Value],
+    game: &Game,
+) -> Result<Option<&'a serde_json::Value>> {
+    let mut candidates = Vec::new();
+    for (i, m) in matches.iter().enumerate() {
+        if http::expect_str(&m["title"], &format!("[{}].title", i))? == game.id {
+            candidates.push(m);
+        }
+    }
+
+    if !game.double_header && candidates.len() <= 1 {
+        return Ok(candidates.pop());
+    }
+
+    if let Some(start_time) = game.start_time {
+        let start_ms = start_time.timestamp_millis();
+        let timed: Vec<_> = candidates
+            .iter()
+            .filter_map(|m| m["date"].as_i64().map(|date| (*m, (date - start_ms).abs())))
+            .collect();
+
+        if !timed.is_empty() {
+            return Ok(timed
+                .into_iter()
+                .filter(|(_, distance)| !game.double_header || *distance <= MAX_START_DIFFERENCE_MS)
+                .min_by_key(|(_, distance)| *distance)
+                .map(|(m, _)| m));
+        }
+    }
+
+    let index = (game.game_number as usize).saturating_sub(1);
+    Ok(candidates.get(index).copied())
+}
@@ -0,0 +1,154 @@
+//! Pitch-by-pitch detail of a plate appearance.
+
+use serde::Serialize;
+
+use crate::feed::models::Play;
+
+/// Half the width of home plate plus the radius of a ball, in feet.
+const ZONE_HALF_WIDTH: f64 = 0.83;
+
+/// Plot area, in feet from the catcher's view.
+const PLOT_X: (f64, f64) = (-2.0, 2.0);
+const PLOT_Z: (f64, f64) = (0.5, 4.5);
+const PLOT_COLUMNS: usize = 33;
+const PLOT_ROWS: usize = 17;
+
+/// A single pitch with its tracking data.
+#[derive(Debug, Clone, Serialize)]
+pub struct Pitch {
+    /// 1-based pitch number within the plate appearance.
+    pub number: u32,
+    /// Pitch type code, e.g. `FF`.
+    pub pitch_code: Option<String>,
+    /// Pitch type, e.g. `Four-Seam Fastball`.
+    pub pitch_type: Option<String>,
+    /// Release velocity in mph.
+    pub velocity: Option<f64>,
+    /// Spin rate in rpm.
+    pub spin_rate: Option<f64>,
+    /// Gameday zone, 1-9 inside the strike zone and 11-14 outside.
+    pub zone: Option<u32>,
+    /// Umpire call or result, e.g. `Called Strike`, `In play, out(s)`.
+    pub call: String,
+    /// Count after the pitch.
+    pub balls: u32,
+    pub strikes: u32,
+    /// Horizontal location in feet from the middle of the plate.
+    pub p_x: Option<f64>,
+    /// Height in feet above the ground.
+    pub p_z: Option<f64>,
+    pub strike_zone_top: Option<f64>,
+    pub strike_zone_bottom: Option<f64>,
+}
+
+impl Play {
+    /// The pitches thrown in this plate appearance, skipping pickoffs,
+    /// substitutions and other non-pitch events.
+    pub fn pitches(&self) -> Vec<Pitch> {
+        self.play_events
+            .iter()
+            .filter(|event| event.is_pitch)
+            .enumerate()
+            .map(|(i, event)| {
+                let details = &event.details;
+                let data = event.pitch_data.as_ref();
+                let pitch_type = details.pitch_type.as_ref();
+
+                Pitch {
+                    number: event.pitch_number.unwrap_or(i as u32 + 1),
+                    pitch_code: pitch_type.and_then(|t| t.code.clone()),
+                    pitch_type: pitch_type.and_then(|t| t.description.clone()),
+                    velocity: data.and_then(|d| d.start_speed),
+                    spin_rate: data.and_then(|d| d.breaks.spin_rate),
+                    zone: data.and_then(|d| d.zone),
+                    call: details
+                        .call
+                        .as_ref()
+                        .and_then(|c| c.description.clone())
+                        .or_else(|| details.description.clone())
+                        .unwrap_or_default(),
+                    balls: event.count.balls,
+                    strikes: event.count.strikes,
+                    p_x: data.and_then(|d| d.coordinates.p_x),
+                    p_z: data.and_then(|d| d.coordinates.p_z),
+                    strike_zone_top: data.and_then(|d| d.strike_zone_top),
+                    strike_zone_bottom: data.and_then(|d| d.strike_zone_bottom),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Draws the strike zone from the catcher's view with each located pitch
+/// marked by its number (`1`-`9`, then `a`, `b`, ...).
+pub fn strike_zone_plot(pitches: &[Pitch]) -> String {
+    let average = |values: Vec<f64>, default: f64| {
+        if values.is_empty() {
+            default
+        } else {
+            values.iter().sum::<f64>() / values.len() as f64
+        }
+    };
+    let top = average(
+        pitches.iter().filter_map(|p| p.strike_zone_top).collect(),
+        3.5,
+    );
+    let bottom = average(
+        pitches
+            .iter()
+            .filter_map(|p| p.strike_zone_bottom)
+            .collect(),
+        1.5,
+    );
+
+    let column = |x: f64| {
+        let scaled = (x - PLOT_X.0) / (PLOT_X.1 - PLOT_X.0) * (PLOT_COLUMNS - 1) as f64;
+        scaled.round().clamp(0.0, (PLOT_COLUMNS - 1) as f64) as usize
+    };
+    let row = |z: f64| {
+        let scaled = (PLOT_Z.1 - z) / (PLOT_Z.1 - PLOT_Z.0) * (PLOT_ROWS - 1) as f64;
+        scaled.round().clamp(0.0, (PLOT_ROWS - 1) as f64) as usize
+    };
+
+    let mut grid = vec![vec![' '; PLOT_COLUMNS]; PLOT_ROWS];
+
+    let (left, right) = (column(-ZONE_HALF_WIDTH), column(ZONE_HALF_WIDTH));
+    let (upper, lower) = (row(top), row(bottom));
+    for r in [upper, lower] {
+        grid[r][left..=right].fill('-');
+    }
+    for line in grid.iter_mut().take(lower).skip(upper + 1) {
+        line[left] = '|';
+        line[right] = '|';
+    }
+    for (r, c) in [(upper, left), (upper, right), (lower, left), (lower, right)] {
+        grid[r][c] = '+';
+    }
+
+    for pitch in pitches {
+        if let (Some(x), Some(z)) = (pitch.p_x, pitch.p_z) {
+            grid[row(z)][column(x)] = pitch_marker(pitch.number);
+        }
+    }
+
+    let border = format!("+{}+", "-".repeat(PLOT_COLUMNS));
+    let mut plot = String::from("Catcher's view\n");
+    plot.push_str(&border);
+    plot.push('\n');
+    for line in grid {
+        plot.push('|');
+        plot.extend(line);
+        plot.push_str("|\n");
+    }
+    plot.push_str(&border);
+    plot.push('\n');
+    plot
+}
+
+fn pitch_marker(number: u32) -> char {
+    match number {
+        // The feed numbers pitches from 1, but a 0 must not underflow below.
+        0..=9 => char::from_digit(number, 10).unwrap(),
+        n => char::from_u32('a' as u32 + (n - 10) % 26).unwrap(),
+    }
+}
@@ -0,0 +1,372 @@
+//! Fetching the MLB schedule and turning it into a list of [`Game`]s.
+
+pub mod models;
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::cache;
+use crate::client::Client;
+use crate::error::Result;
+use crate::linescore::LinescoreTable;
+use models::{ScheduleGame, ScheduleResponse, Team};
+
+/// A game that can be listed and matched against a streaming source.
+#[derive(Debug, Clone, Serialize)]
+pub struct Game {
+    /// Unique statsapi identifier of the game.
+    pub game_pk: u64,
+    /// Official date of the game as `YYYY-MM-DD`.
+    pub date: String,
+    /// Scheduled first pitch in the requested time zone, when the schedule
+    /// provides one.
+    pub start_time: Option<DateTime<FixedOffset>>,
+    pub state: GameState,
+    /// 1 or 2; only meaningful when [`Game::double_header`] is set.
+    pub game_number: u32,
+    /// Whether this game is part of a doubleheader (traditional or split).
+    pub double_header: bool,
+    /// Current (or final) inning, once the game has started.
+    pub inning: Option<u32>,
+    /// `Top`, `Middle`, `Bottom` or `End` of [`Game::inning`].
+    pub inning_half: Option<String>,
+    /// Inning-by-inning score, once the game has started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linescore: Option<LinescoreTable>,
+    /// The home club.
+    pub home: GameTeam,
+    /// The visiting club.
+    pub away: GameTeam,
+    /// Human readable one-line summary, e.g. `NYY (3) vs BOS (2) | Top of 5th`.
+    pub title: String,
+    /// Title used by the streaming site, `Home Full Name vs Away Full Name`.
+    /// Both games of a doubleheader share it, so look games up by
+    /// [`Game::game_pk`] instead.
+    pub id: String,
+}
+
+/// Coarse state of a game, from the schedule's `abstractGameCode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GameState {
+    /// Not started yet (`P`).
+    Preview,
+    /// In progress (`L`).
+    Live,
+    /// Completed, postponed or cancelled (`F`).
+    Final,
+}
+
+impl GameState {
+    /// Every state, for listings that should not filter by status.
+    pub const ALL: [GameState; 3] = [GameState::Preview, GameState::Live, GameState::Final];
+
+    fn from_code(code: Option<&str>) -> Self {
+        match code {
+            Some("P") => GameState::Preview,
+            Some("F") => GameState::Final,
+            _ => GameState::Live,
+        }
+    }
+}
+
+/// A club taking part in a [`Game`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameTeam {
+    pub id: u64,
+    /// Full club name, e.g. `New York Yankees`.
+    pub name: String,
+    /// Club name without the location, e.g. `Yankees`.
+    pub team_name: String,
+    /// Short code, e.g. `NYY`.
+    pub abbreviation: String,
+    pub score: u32,
+}
+
+impl GameTeam {
+    /// Whether `query` names this club by abbreviation, id, full name or club
+    /// name. Comparisons ignore case.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim();
+
+        self.abbreviation.eq_ignore_ascii_case(query)
+            || self.name.eq_ignore_ascii_case(query)
+            || self.team_name.eq_ignore_ascii_case(query)
+            || query.parse::<u64>() == Ok(self.id)
+    }
+}
+
+/// A club without a score yet. Missing names fall back to [`Team::label`].
+impl From<&Team> for GameTeam {
+    fn from(team: &Team) -> Self {
+        GameTeam {
+            id: team.id,
+            name: team.name.clone().unwrap_or_else(|| team.label()),
+            team_name: team.team_name.clone().unwrap_or_else(|| team.label()),
+            abbreviation: team.label(),
+            score: 0,
+        }
+    }
+}
+
+impl Game {
+    /// Whether either club matches `team`, see [`GameTeam::matches`].
+    pub fn involves(&self, team: &str) -> bool {
+        self.home.matches(team) || self.away.matches(team)
+    }
+
+    /// Whether the score, state or inning differs from `previous`, a
+    /// snapshot of the same game from an earlier refresh.
+    pub fn progressed_since(&self, previous: &Game) -> bool {
+        self.home.score != previous.home.score
+            || self.away.score != previous.away.score
+            || self.state != previous.state
+            || self.inning != previous.inning
+            || self.inning_half != previous.inning_half
+    }
+}
+
+/// The `gamePk`s of games in `current` that [progressed](Game::progressed_since)
+/// since `previous`. Games missing from `previous` are not reported.
+pub fn changed_games(previous: &[Game], current: &[Game]) -> HashSet<u64> {
+    current
+        .iter()
+        .filter(|game| {
+            previous
+                .iter()
+                .find(|p| p.game_pk == game.game_pk)
+                .is_some_and(|p| game.progressed_since(p))
+        })
+        .map(|game| game.game_pk)
+        .collect()
+}
+
+/// Picks a game by its 1-based position in `games` or, failing that, by its
+/// `gamePk`.
+pub fn find_game<'a>(games: &'a [Game], selector: &str) -> Option<&'a Game> {
+    let n: u64 = selector.trim().parse().ok()?;
+
+    if n >= 1 && n <= games.len() as u64 {
+        return Some(&games[n as usize - 1]);
+    }
+
+    games.iter().find(|game| game.game_pk == n)
+}
+
+impl Client {
+    /// Fetches the schedule for `date_string` (`YYYY-MM-DD`) and returns the
+    /// games whose state is one of `states`. Start times in titles are shown
+    /// in `tz`.
+    pub async fn get_schedule(
+        &self,
+        date_string: &str,
+        states: &[GameState],
+        tz: Tz,
+    ) -> Result<Vec<Game>> {
+        self.fetch_games(&format!("date={}", date_string), states, tz)
+            .await
+    }
+
+    /// Like [`Client::get_schedule`] but for every date from `start_date` to
+    /// `end_date` inclusive. Games are returned in date order.
+    pub async fn get_schedule_range(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        states: &[GameState],
+        tz: Tz,
+    ) -> Result<Vec<Game>> {
+        self.fetch_games(
+            &format!("startDate={}&endDate={}", start_date, end_date),
+            states,
+            tz,
+        )
+        .await
+    }
+
+    async fn fetch_games(
+        &self,
+        date_query: &str,
+        states: &[GameState],
+        tz: Tz,
+    ) -> Result<Vec<Game>> {
+        let schedule: ScheduleResponse = self
+            .get_json(
+                &self.statsapi(&format!(
+                    "/v1/schedule?sportId=1&hydrate=team,linescore&{}",
+                    date_query
+                )),
+                schedule_ttl,
+            )
+            .await?;
+
+        let mut games: Vec<Game> = Vec::new();
+
+        for date in &schedule.dates {
+            for game in &date.games {
+                let state = GameState::from_code(game.status.abstract_game_code.as_deref());
+                if !states.contains(&state) {
+                    continue;
+                }
+
+                match game_from_schedule(&date.date, game, tz) {
+                    Some(g) => games.push(g),
+                    None => eprintln!(
+                        "warning: skipping game {} with incomplete team data",
+                        game.game_pk
+                    ),
+                }
+            }
+        }
+
+        Ok(games)
+    }
+}
+
+/// How long a schedule stays current: briefly while games are live, a
+/// little longer while some have yet to start, and for good once every
+/// game is final.
+fn schedule_ttl(schedule: &ScheduleResponse) -> Duration {
+    let mut states = schedule
+        .dates
+        .iter()
+        .flat_map(|date| &date.games)
+        .map(|game| GameState::from_code(game.status.abstract_game_code.as_deref()))
+        .peekable();
+
+    if states.peek().is_none() {
+        return cache::PREVIEW_TTL;
+    }
+
+    let mut ttl = cache::FINAL_TTL;
+    for state in states {
+        match state {
+            GameState::Live => return cache::LIVE_TTL,
+            GameState::Preview => ttl = cache::PREVIEW_TTL,
+            GameState::Final => {}
+        }
+    }
+    ttl
+}
+
+fn game_from_schedule(date: &str, game: &ScheduleGame, tz: Tz) -> Option<Game> {
+    let home = &game.teams.home;
+    let away = &game.teams.away;
+
+    let home_team = home.team.abbreviation.as_deref()?;
+    let away_team = away.team.abbreviation.as_deref()?;
+    let home_team_full_name = home.team.name.as_deref()?;
+    let away_team_full_name = away.team.name.as_deref()?;
+
+    let home_team_score = home.score.unwrap_or(0);
+    let away_team_score = away.score.unwrap_or(0);
+
+    let state = GameState::from_code(game.status.abstract_game_code.as_deref());
+    let start_time = game
+        .game_date
+        .as_deref()
+        .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+        .map(|d| d.with_timezone(&tz));
+
+    let first_pitch = match start_time {
+        Some(t) => t.format("%-I:%M %p %Z").to_string(),
+        None => "TBD".to_string(),
+    };
+
+    let game_number = game.game_number.unwrap_or(1);
+    let double_header = matches!(game.double_header.as_deref(), Some("Y" | "S"));
+    let game_label = if double_header {
+        format!(" | Game {}", game_number)
+    } else {
+        String::new()
+    };
+
+    let title = match state {
+        GameState::Preview => format!(
+            "{} vs {}{} | {}",
+            away_team, home_team, game_label, first_pitch
+        ),
+        GameState::Live => format!(
+            "{} ({}) vs {} ({}){} | {} | {}",
+            away_team,
+            away_team_score,
+            home_team,
+            home_team_score,
+            game_label,
+            live_progress(game),
+            first_pitch
+        ),
+        GameState::Final => format!(
+            "{} ({}) vs {} ({}){} | {} | {}",
+            away_team,
+            away_team_score,
+            home_team,
+            home_team_score,
+            game_label,
+            final_label(game),
+            first_pitch
+        ),
+    };
+
+    Some(Game {
+        game_pk: game.game_pk,
+        date: date.to_string(),
+        start_time: start_time.map(|t| t.fixed_offset()),
+        state,
+        game_number,
+        double_header,
+        inning: game.linescore.as_ref().and_then(|l| l.current_inning),
+        inning_half: game.linescore.as_ref().and_then(|l| l.inning_half.clone()),
+        linescore: game
+            .linescore
+            .as_ref()
+            .filter(|l| !l.innings.is_empty())
+            .map(|l| LinescoreTable::new(l, away_team, home_team, state == GameState::Final)),
+        home: GameTeam {
+            score: home_team_score,
+            ..GameTeam::from(&home.team)
+        },
+        away: GameTeam {
+            score: away_team_score,
+            ..GameTeam::from(&away.team)
+        },
+        title,
+        id: format!("{} vs {}", home_team_full_name, away_team_full_name),
+    })
+}
+
+fn live_progress(game: &ScheduleGame) -> String {
+    let (inning, inning_half) = match &game.linescore {
+        Some(linescore) => (
+            linescore.current_inning_ordinal.as_deref().unwrap_or("N/A"),
+            linescore.inning_half.as_deref().unwrap_or("Top"),
+        ),
+        None => ("N/A", "Top"),
+    };
+
+    let inning_char = if inning_half == "Bottom" {
+        "Bottom of"
+    } else {
+        "Top of"
+    };
+
+    format!("{} {}", inning_char, inning)
+}
+
+/// `Final`, `F/10` for extra innings, or the detailed state for games that
+/// ended without being played out (postponed, cancelled, ...).
+fn final_label(game: &ScheduleGame) -> String {
+    let detailed = game.status.detailed_state.as_deref().unwrap_or("Final");
+    if !detailed.starts_with("Final") && detailed != "Game Over" {
+        return detailed.to_string();
+    }
+
+    match game.linescore.as_ref().and_then(|l| l.current_inning) {
+        Some(inning) if inning != 9 => format!("F/{}", inning),
+        _ => "Final".to_string(),
+    }
+}
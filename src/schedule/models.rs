@@ -0,0 +1,179 @@
+//! Typed models for the statsapi.mlb.com schedule response.
+
+use serde::Deserialize;
+
+/// Top-level body of `/api/v1/schedule`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleResponse {
+    #[serde(default)]
+    pub dates: Vec<ScheduleDate>,
+}
+
+/// All games scheduled on a single calendar date.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleDate {
+    pub date: String,
+    #[serde(default, deserialize_with = "skip_malformed_games")]
+    pub games: Vec<ScheduleGame>,
+}
+
+/// A single game entry as returned by the schedule endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleGame {
+    pub game_pk: u64,
+    pub game_date: Option<String>,
+    pub game_number: Option<u32>,
+    pub double_header: Option<String>,
+    pub status: GameStatus,
+    pub teams: ScheduleTeams,
+    pub linescore: Option<Linescore>,
+}
+
+/// Coarse and detailed status of a game.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameStatus {
+    pub abstract_game_code: Option<String>,
+    pub abstract_game_state: Option<String>,
+    pub detailed_state: Option<String>,
+    pub status_code: Option<String>,
+}
+
+/// The home and away sides of a game.
+#[derive(Debug, Deserialize)]
+pub struct ScheduleTeams {
+    pub home: TeamSide,
+    pub away: TeamSide,
+}
+
+/// One side of a game: the club and its current score.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamSide {
+    pub team: Team,
+    pub score: Option<u32>,
+    pub is_winner: Option<bool>,
+}
+
+/// A club as hydrated by `hydrate=team`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    pub id: u64,
+    pub name: Option<String>,
+    pub abbreviation: Option<String>,
+    pub team_name: Option<String>,
+}
+
+impl Team {
+    /// Abbreviation, falling back to the full name and then the id.
+    pub fn label(&self) -> String {
+        self.abbreviation
+            .clone()
+            .or_else(|| self.name.clone())
+            .unwrap_or_else(|| self.id.to_string())
+    }
+}
+
+/// Linescore as hydrated by `hydrate=linescore`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Linescore {
+    pub current_inning: Option<u32>,
+    pub current_inning_ordinal: Option<String>,
+    pub inning_half: Option<String>,
+    #[serde(default)]
+    pub innings: Vec<LinescoreInning>,
+    pub teams: Option<LinescoreTeams>,
+    pub balls: Option<u32>,
+    pub strikes: Option<u32>,
+    pub outs: Option<u32>,
+    pub offense: Option<Offense>,
+    pub defense: Option<Defense>,
+}
+
+/// The team at bat: current batter and runners on base.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Offense {
+    pub batter: Option<Person>,
+    pub on_deck: Option<Person>,
+    pub first: Option<Person>,
+    pub second: Option<Person>,
+    pub third: Option<Person>,
+}
+
+/// The team in the field.
+#[derive(Debug, Default, Deserialize)]
+pub struct Defense {
+    pub pitcher: Option<Person>,
+}
+
+/// A player reference.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    pub id: u64,
+    pub full_name: Option<String>,
+}
+
+impl Person {
+    /// The player's full name, or their id when the feed omits it.
+    pub fn name(&self) -> String {
+        match &self.full_name {
+            Some(name) => name.clone(),
+            None => format!("#{}", self.id),
+        }
+    }
+}
+
+/// Runs, hits and errors for both halves of one inning.
+#[derive(Debug, Deserialize)]
+pub struct LinescoreInning {
+    pub num: u32,
+    #[serde(default)]
+    pub home: LinescoreInningHalf,
+    #[serde(default)]
+    pub away: LinescoreInningHalf,
+}
+
+/// Runs, hits and errors for one team, either per inning or in total.
+#[derive(Debug, Default, Deserialize)]
+pub struct LinescoreInningHalf {
+    pub runs: Option<u32>,
+    pub hits: Option<u32>,
+    pub errors: Option<u32>,
+}
+
+/// Game totals for each team.
+#[derive(Debug, Deserialize)]
+pub struct LinescoreTeams {
+    #[serde(default)]
+    pub home: LinescoreInningHalf,
+    #[serde(default)]
+    pub away: LinescoreInningHalf,
+}
+
+fn skip_malformed_games<'de, D>(deserializer: D) -> Result<Vec<ScheduleGame>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw: Vec<serde_json::Value> = Deserialize::deserialize(deserializer)?;
+
+    Ok(raw
+        .into_iter()
+        .filter_map(|value| {
+            let game_pk = value["gamePk"].clone();
+            match serde_json::from_value(value) {
+                Ok(game) => Some(game),
+                Err(err) => {
+                    eprintln!("warning: skipping malformed game {}: {}", game_pk, err);
+                    None
+                }
+            }
+        })
+        .collect())
+}
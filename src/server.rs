@@ -0,0 +1,271 @@
+//! Local HTTP API serving schedule and game data as JSON, so several
+//! dashboards can share one set of upstream requests.
+
+mod live;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use axum::Router;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Json, Response};
+use axum::routing::get;
+use chrono_tz::Tz;
+use serde::Deserialize;
+
+use crate::client::Client;
+use crate::dates::{self, DateSpec};
+use crate::error::{Error, Result};
+use crate::feed::Situation;
+use crate::feed::models::LiveFeed;
+use crate::linescore::LinescoreTable;
+use crate::schedule::{Game, GameState};
+
+pub use live::LiveUpdate;
+
+/// Settings for [`serve`].
+#[derive(Debug, Clone)]
+pub struct ServeOptions {
+    /// Address to listen on, e.g. `127.0.0.1:8080`.
+    pub addr: SocketAddr,
+    /// Time zone for start times and for resolving `today`.
+    pub tz: Tz,
+    pub client: Client,
+}
+
+/// Serves the API until the process is stopped:
+///
+/// - `GET /games?date=&status=&team=` lists a day's games
+/// - `GET /games/{gamePk}` is the live situation of one game
+/// - `GET /games/{gamePk}/linescore` is its inning-by-inning linescore
+/// - `GET /games/{gamePk}/events` streams linescore and play updates as
+///   Server-Sent Events
+/// - `GET /games/{gamePk}/ws` streams the same updates over a WebSocket
+pub async fn serve(options: ServeOptions) -> Result<()> {
+    let state = Arc::new(AppState {
+        tz: options.tz,
+        client: options.client,
+        schedules: InFlight::new(),
+        feeds: InFlight::new(),
+        watchers: live::Watchers::default(),
+    });
+
+    let app = Router::new()
+        .route("/games", get(games))
+        .route("/games/{game_pk}", get(game))
+        .route("/games/{game_pk}/linescore", get(linescore))
+        .route("/games/{game_pk}/events", get(live::events))
+        .route("/games/{game_pk}/ws", get(live::websocket))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(options.addr).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+struct AppState {
+    tz: Tz,
+    client: Client,
+    schedules: InFlight<Arc<Vec<Game>>>,
+    feeds: InFlight<Arc<LiveFeed>>,
+    watchers: live::Watchers,
+}
+
+impl AppState {
+    /// Every game on `date`, whatever its state.
+    async fn schedule(&self, date: String) -> Result<Arc<Vec<Game>>> {
+        self.schedules
+            .get_or_fetch(date.clone(), || async move {
+                let games = self
+                    .client
+                    .get_schedule(&date, &GameState::ALL, self.tz)
+                    .await?;
+                Ok(Arc::new(games))
+            })
+            .await
+    }
+
+    async fn feed(&self, game_pk: u64) -> Result<Arc<LiveFeed>> {
+        self.feeds
+            .get_or_fetch(game_pk.to_string(), || async move {
+                Ok(Arc::new(self.client.get_live_feed(game_pk).await?))
+            })
+            .await
+    }
+}
+
+/// Fetches in progress. Concurrent requests for the same key wait for a
+/// single fetch rather than each making their own; reusing responses after
+/// that is left to the client's cache.
+struct InFlight<V> {
+    fetches: Mutex<HashMap<String, Arc<Slot<V>>>>,
+}
+
+/// The value of one fetch once it succeeded; locked while fetching.
+type Slot<V> = tokio::sync::Mutex<Option<V>>;
+
+impl<V: Clone> InFlight<V> {
+    fn new() -> Self {
+        InFlight {
+            fetches: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn get_or_fetch<F, Fut>(&self, key: String, fetch: F) -> Result<V>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V>>,
+    {
+        let slot = self
+            .fetches
+            .lock()
+            .expect("in-flight lock poisoned")
+            .entry(key.clone())
+            .or_default()
+            .clone();
+
+        let mut value = slot.lock().await;
+        if let Some(value) = &*value {
+            return Ok(value.clone());
+        }
+
+        let fetched = fetch().await;
+        if let Ok(fetched) = &fetched {
+            *value = Some(fetched.clone());
+        }
+
+        // Whoever asks next starts a new fetch, so only fetches in progress
+        // are ever held.
+        let mut fetches = self.fetches.lock().expect("in-flight lock poisoned");
+        if fetches
+            .get(&key)
+            .is_some_and(|other| Arc::ptr_eq(other, &slot))
+        {
+            fetches.remove(&key);
+        }
+        fetched
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GamesQuery {
+    /// YYYY-MM-DD, today, yesterday, tomorrow or +N/-N days.
+    date: Option<String>,
+    /// Comma separated states, e.g. `live,final`.
+    status: Option<String>,
+    /// Comma separated teams, by abbreviation, id or name.
+    team: Option<String>,
+}
+
+async fn games(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<GamesQuery>,
+) -> std::result::Result<Json<Vec<Game>>, ApiError> {
+    let today = dates::today_in(state.tz);
+    let date = match &query.date {
+        Some(date) => date
+            .parse::<DateSpec>()
+            .map_err(|err| ApiError::bad_request(err.to_string()))?
+            .resolve(today),
+        None => today,
+    };
+    let states = match &query.status {
+        Some(status) => parse_states(status)?,
+        None => GameState::ALL.to_vec(),
+    };
+    let teams: Vec<&str> = query
+        .team
+        .as_deref()
+        .map(|teams| teams.split(',').map(str::trim).collect())
+        .unwrap_or_default();
+
+    let schedule = state.schedule(date.to_string()).await?;
+    let games = schedule
+        .iter()
+        .filter(|game| states.contains(&game.state))
+        .filter(|game| teams.is_empty() || teams.iter().any(|team| game.involves(team)))
+        .cloned()
+        .collect();
+
+    Ok(Json(games))
+}
+
+async fn game(
+    State(state): State<Arc<AppState>>,
+    Path(game_pk): Path<u64>,
+) -> std::result::Result<Json<Situation>, ApiError> {
+    Ok(Json(state.feed(game_pk).await?.situation()))
+}
+
+async fn linescore(
+    State(state): State<Arc<AppState>>,
+    Path(game_pk): Path<u64>,
+) -> std::result::Result<Json<LinescoreTable>, ApiError> {
+    Ok(Json(state.feed(game_pk).await?.linescore_table()))
+}
+
+fn parse_states(status: &str) -> std::result::Result<Vec<GameState>, ApiError> {
+    let mut states = Vec::new();
+    for name in status.split(',').map(str::trim) {
+        match name {
+            "preview" => states.push(GameState::Preview),
+            "live" => states.push(GameState::Live),
+            "final" => states.push(GameState::Final),
+            "all" => states.extend(GameState::ALL),
+            other => {
+                return Err(ApiError::bad_request(format!(
+                    "invalid status `{}`; expected preview, live, final or all",
+                    other
+                )));
+            }
+        }
+    }
+    Ok(states)
+}
+
+/// An error response, rendered as `{"error": message}`.
+struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    fn bad_request(message: String) -> Self {
+        ApiError {
+            status: StatusCode::BAD_REQUEST,
+            message,
+        }
+    }
+}
+
+impl From<Error> for ApiError {
+    fn from(err: Error) -> Self {
+        let status = match &err {
+            Error::HttpStatus { status, .. } if *status == reqwest::StatusCode::NOT_FOUND => {
+                StatusCode::NOT_FOUND
+            }
+            Error::NoMatch(_) => StatusCode::NOT_FOUND,
+            Error::InvalidSelection(_) => StatusCode::BAD_REQUEST,
+            Error::Network { .. }
+            | Error::HttpStatus { .. }
+            | Error::Decode { .. }
+            | Error::Shape { .. } => StatusCode::BAD_GATEWAY,
+            Error::Io(_) | Error::Config(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        ApiError {
+            status,
+            message: err.to_string(),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = serde_json::json!({ "error": self.message });
+        (self.status, Json(body)).into_response()
+    }
+}
@@ -0,0 +1,196 @@
+//! Pushing linescore and play updates for a game to SSE and WebSocket
+//! clients as they happen.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, State};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use futures_util::stream::{self, Stream, StreamExt};
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+use super::{ApiError, AppState};
+use crate::cache;
+use crate::feed::{PlayFilter, PlaySummary};
+use crate::linescore::LinescoreTable;
+
+/// Updates a slow client may fall behind by before it skips ahead.
+const CHANNEL_CAPACITY: usize = 64;
+
+/// A change pushed to clients, as `{"type": ..., "data": ...}`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum LiveUpdate {
+    /// The linescore changed; always the first update a client receives.
+    Linescore(LinescoreTable),
+    /// A plate appearance was completed.
+    Play(PlaySummary),
+}
+
+impl LiveUpdate {
+    fn name(&self) -> &'static str {
+        match self {
+            LiveUpdate::Linescore(_) => "linescore",
+            LiveUpdate::Play(_) => "play",
+        }
+    }
+}
+
+/// One poller per followed game, shared by all of its clients.
+#[derive(Default)]
+pub(super) struct Watchers {
+    channels: Mutex<HashMap<u64, broadcast::Sender<LiveUpdate>>>,
+}
+
+impl AppState {
+    /// Subscribes to `game_pk`, starting its poller if nobody else follows it.
+    fn subscribe(self: &Arc<Self>, game_pk: u64) -> broadcast::Receiver<LiveUpdate> {
+        let mut channels = self
+            .watchers
+            .channels
+            .lock()
+            .expect("watchers lock poisoned");
+        if let Some(sender) = channels.get(&game_pk) {
+            return sender.subscribe();
+        }
+
+        let (sender, receiver) = broadcast::channel(CHANNEL_CAPACITY);
+        channels.insert(game_pk, sender.clone());
+        tokio::spawn(poll(self.clone(), game_pk, sender));
+        receiver
+    }
+
+    /// Subscribes to `game_pk` and returns its updates, starting with the
+    /// current linescore. Fails if the game cannot be fetched.
+    async fn updates(
+        self: &Arc<Self>,
+        game_pk: u64,
+    ) -> std::result::Result<impl Stream<Item = LiveUpdate> + use<>, ApiError> {
+        let receiver = self.subscribe(game_pk);
+        let current = LiveUpdate::Linescore(self.feed(game_pk).await?.linescore_table());
+
+        let updates = stream::unfold(receiver, |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(update) => return Some((update, receiver)),
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        });
+        Ok(stream::once(async { current }).chain(updates))
+    }
+}
+
+/// Polls the live feed of `game_pk` and broadcasts what changed, until the
+/// game is final or nobody is listening.
+async fn poll(state: Arc<AppState>, game_pk: u64, sender: broadcast::Sender<LiveUpdate>) {
+    let mut linescore = None;
+    let mut last_play = None;
+    let mut first = true;
+
+    loop {
+        let mut is_final = false;
+        match state.feed(game_pk).await {
+            Ok(feed) => {
+                // The first poll only sets the baseline; clients already get
+                // the current linescore when they subscribe.
+                let table = feed.linescore_table();
+                let json = serde_json::to_value(&table).expect("linescores always serialize");
+                if linescore.as_ref() != Some(&json) {
+                    linescore = Some(json);
+                    if !first {
+                        let _ = sender.send(LiveUpdate::Linescore(table));
+                    }
+                }
+
+                let plays = feed.plays(&PlayFilter {
+                    after: last_play,
+                    ..Default::default()
+                });
+                if let Some(last) = plays.last() {
+                    last_play = Some(last.at_bat_index);
+                }
+                if !first {
+                    for play in plays {
+                        let _ = sender.send(LiveUpdate::Play(play));
+                    }
+                }
+
+                first = false;
+                is_final = feed.is_final();
+            }
+            Err(err) => eprintln!("warning: refreshing game {} failed: {}", game_pk, err),
+        }
+
+        {
+            let mut channels = state
+                .watchers
+                .channels
+                .lock()
+                .expect("watchers lock poisoned");
+            if is_final || sender.receiver_count() == 0 {
+                channels.remove(&game_pk);
+                return;
+            }
+        }
+
+        tokio::time::sleep(cache::LIVE_TTL).await;
+    }
+}
+
+/// `GET /games/{gamePk}/events`: updates as Server-Sent Events named
+/// `linescore` and `play`.
+pub(super) async fn events(
+    State(state): State<Arc<AppState>>,
+    Path(game_pk): Path<u64>,
+) -> std::result::Result<Response, ApiError> {
+    let updates = state.updates(game_pk).await?.map(|update| {
+        let event = Event::default()
+            .event(update.name())
+            .json_data(&update)
+            .expect("updates always serialize");
+        Ok::<_, Infallible>(event)
+    });
+
+    Ok(Sse::new(updates)
+        .keep_alive(KeepAlive::default())
+        .into_response())
+}
+
+/// `GET /games/{gamePk}/ws`: updates as JSON text messages.
+pub(super) async fn websocket(
+    State(state): State<Arc<AppState>>,
+    Path(game_pk): Path<u64>,
+    upgrade: WebSocketUpgrade,
+) -> std::result::Result<Response, ApiError> {
+    let updates = state.updates(game_pk).await?;
+    Ok(upgrade.on_upgrade(move |socket| forward(socket, updates)))
+}
+
+/// Sends `updates` to the socket until either side is done.
+async fn forward(mut socket: WebSocket, updates: impl Stream<Item = LiveUpdate>) {
+    let mut updates = std::pin::pin!(updates);
+
+    loop {
+        tokio::select! {
+            update = updates.next() => {
+                let Some(update) = update else { break };
+                let json = serde_json::to_string(&update).expect("updates always serialize");
+                if socket.send(Message::Text(json.into())).await.is_err() {
+                    return;
+                }
+            }
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+
+    let _ = socket.send(Message::Close(None)).await;
+}
@@ -0,0 +1,54 @@
+//! Listing stream sources and embed URLs for a game.
+
+use crate::cache;
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::http;
+use crate::matching;
+use crate::schedule::Game;
+
+impl Client {
+    /// Returns the stream sources listed for the match corresponding to
+    /// `game`.
+    ///
+    /// Fails with [`Error::NoMatch`] when the streaming site has no such match.
+    pub async fn get_sources(&self, game: &Game) -> Result<Vec<serde_json::Value>> {
+        let json: serde_json::Value = self
+            .get_json(&self.streams("/matches/baseball"), |_| cache::STREAMS_TTL)
+            .await?;
+
+        let matches = http::expect_array(&json, "$")?;
+
+        match matching::find_match(matches, game)? {
+            Some(m) => Ok(http::expect_array(&m["sources"], "sources")?.clone()),
+            None => Err(Error::NoMatch(format!(
+                "{} (gamePk {})",
+                game.id, game.game_pk
+            ))),
+        }
+    }
+
+    /// Resolves each source into the embed URLs of its streams.
+    pub async fn get_streams(&self, sources: &[serde_json::Value]) -> Result<Vec<String>> {
+        let mut embed_urls = Vec::new();
+
+        for (i, source) in sources.iter().enumerate() {
+            let source_id = http::expect_str(&source["id"], &format!("sources[{}].id", i))?;
+            let source_type =
+                http::expect_str(&source["source"], &format!("sources[{}].source", i))?;
+
+            let url = self.streams(&format!("/stream/{}/{}", source_type, source_id));
+
+            let json: serde_json::Value = self.get_json(&url, |_| cache::STREAMS_TTL).await?;
+
+            let streams = http::expect_array(&json, "$")?;
+            for (j, stream) in streams.iter().enumerate() {
+                let embed_url =
+                    http::expect_str(&stream["embedUrl"], &format!("[{}].embedUrl", j))?;
+                embed_urls.push(embed_url.to_string());
+            }
+        }
+
+        Ok(embed_urls)
+    }
+}
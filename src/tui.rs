@@ -0,0 +1,392 @@
+//! Full-screen terminal UI: a navigable game list next to linescore,
+//! boxscore and play-by-play tabs for the selected game.
+
+use std::time::{Duration, Instant};
+
+use chrono::{NaiveDate, Utc};
+use chrono_tz::Tz;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Tabs, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::boxscore::BoxScore;
+use crate::client::Client;
+use crate::config::Config;
+use crate::dates;
+use crate::error::Result;
+use crate::feed::PlayFilter;
+use crate::feed::models::LiveFeed;
+use crate::schedule::{Game, GameState};
+
+/// How often the terminal is checked for key presses.
+const INPUT_POLL: Duration = Duration::from_millis(250);
+
+/// Settings for [`run`].
+#[derive(Debug, Clone)]
+pub struct TuiOptions {
+    pub client: Client,
+    /// Date shown first; can be changed with the arrow keys.
+    pub date: NaiveDate,
+    pub tz: Tz,
+    /// Initial team filter, by abbreviation, id or name.
+    pub teams: Vec<String>,
+    /// Interval between automatic refreshes.
+    pub refresh: Duration,
+}
+
+/// Takes over the terminal until the user quits. The config is read first,
+/// so a malformed one is reported before the screen is cleared.
+pub async fn run(options: TuiOptions) -> Result<()> {
+    let config = Config::load()?;
+    let mut terminal = ratatui::init();
+    let result = App::new(options, config).run(&mut terminal).await;
+    ratatui::restore();
+    result
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tab {
+    Linescore,
+    Boxscore,
+    Plays,
+}
+
+impl Tab {
+    const ALL: [Tab; 3] = [Tab::Linescore, Tab::Boxscore, Tab::Plays];
+
+    fn title(self) -> &'static str {
+        match self {
+            Tab::Linescore => "1 Linescore",
+            Tab::Boxscore => "2 Box",
+            Tab::Plays => "3 Plays",
+        }
+    }
+
+    fn index(self) -> usize {
+        Tab::ALL.iter().position(|t| *t == self).unwrap_or(0)
+    }
+}
+
+struct App {
+    options: TuiOptions,
+    config: Config,
+    games: Vec<Game>,
+    list: ListState,
+    tab: Tab,
+    scroll: u16,
+    feed: Option<(u64, LiveFeed)>,
+    boxscore: Option<(u64, BoxScore)>,
+    /// Team filter being typed, while the prompt is open.
+    filter_input: Option<String>,
+    status: String,
+    last_refresh: Instant,
+    quit: bool,
+}
+
+impl App {
+    fn new(options: TuiOptions, config: Config) -> Self {
+        App {
+            options,
+            config,
+            games: Vec::new(),
+            list: ListState::default(),
+            tab: Tab::Linescore,
+            scroll: 0,
+            feed: None,
+            boxscore: None,
+            filter_input: None,
+            status: String::new(),
+            last_refresh: Instant::now(),
+            quit: false,
+        }
+    }
+
+    async fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        self.refresh().await;
+
+        while !self.quit {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if event::poll(INPUT_POLL)?
+                && let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                self.handle_key(key).await;
+            }
+
+            if self.last_refresh.elapsed() >= self.options.refresh {
+                self.refresh().await;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn selected(&self) -> Option<&Game> {
+        self.list.selected().and_then(|i| self.games.get(i))
+    }
+
+    /// Reloads the schedule, keeping the same game selected, then the
+    /// details of the selected game.
+    async fn refresh(&mut self) {
+        self.last_refresh = Instant::now();
+        let selected_pk = self.selected().map(|game| game.game_pk);
+
+        match self
+            .options
+            .client
+            .get_schedule(
+                &self.options.date.to_string(),
+                &GameState::ALL,
+                self.options.tz,
+            )
+            .await
+        {
+            Ok(mut games) => {
+                if !self.options.teams.is_empty() {
+                    games.retain(|game| self.options.teams.iter().any(|t| game.involves(t)));
+                }
+                games.sort_by_key(|game| !self.config.is_favorite(game));
+
+                let index = selected_pk
+                    .and_then(|pk| games.iter().position(|game| game.game_pk == pk))
+                    .or(if games.is_empty() { None } else { Some(0) });
+                self.games = games;
+                self.list.select(index);
+                self.status = format!(
+                    "Updated {}",
+                    Utc::now()
+                        .with_timezone(&self.options.tz)
+                        .format("%H:%M:%S")
+                );
+            }
+            Err(err) => self.status = format!("Refresh failed: {}", err),
+        }
+
+        self.load_detail(true).await;
+    }
+
+    /// Fetches what the current tab needs for the selected game. Cached data
+    /// is reused unless `force` is set.
+    async fn load_detail(&mut self, force: bool) {
+        let Some((game_pk, state)) = self.selected().map(|game| (game.game_pk, game.state)) else {
+            return;
+        };
+
+        let result = match self.tab {
+            Tab::Linescore | Tab::Plays => {
+                if !force && self.feed.as_ref().is_some_and(|(pk, _)| *pk == game_pk) {
+                    return;
+                }
+                self.options
+                    .client
+                    .get_live_feed(game_pk)
+                    .await
+                    .map(|feed| self.feed = Some((game_pk, feed)))
+            }
+            Tab::Boxscore => {
+                if !force && self.boxscore.as_ref().is_some_and(|(pk, _)| *pk == game_pk) {
+                    return;
+                }
+                self.options
+                    .client
+                    .get_boxscore(game_pk, state)
+                    .await
+                    .map(|boxscore| self.boxscore = Some((game_pk, boxscore)))
+            }
+        };
+
+        if let Err(err) = result {
+            self.status = format!("Could not load game {}: {}", game_pk, err);
+        }
+    }
+
+    async fn handle_key(&mut self, key: KeyEvent) {
+        if let Some(input) = &mut self.filter_input {
+            match key.code {
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Enter => {
+                    self.options.teams = input
+                        .split(',')
+                        .map(|t| t.trim().to_string())
+                        .filter(|t| !t.is_empty())
+                        .collect();
+                    self.filter_input = None;
+                    self.refresh().await;
+                }
+                KeyCode::Esc => self.filter_input = None,
+                _ => {}
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1).await,
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1).await,
+            KeyCode::Tab => {
+                self.select_tab(Tab::ALL[(self.tab.index() + 1) % Tab::ALL.len()])
+                    .await
+            }
+            KeyCode::BackTab => {
+                self.select_tab(Tab::ALL[(self.tab.index() + Tab::ALL.len() - 1) % Tab::ALL.len()])
+                    .await
+            }
+            KeyCode::Char('1') => self.select_tab(Tab::Linescore).await,
+            KeyCode::Char('2') => self.select_tab(Tab::Boxscore).await,
+            KeyCode::Char('3') => self.select_tab(Tab::Plays).await,
+            KeyCode::Left | KeyCode::Char('h') => self.change_date(-1).await,
+            KeyCode::Right | KeyCode::Char('l') => self.change_date(1).await,
+            KeyCode::Char('t') => {
+                self.options.date = dates::today_in(self.options.tz);
+                self.refresh().await;
+            }
+            KeyCode::Char('/') | KeyCode::Char('f') => {
+                self.filter_input = Some(self.options.teams.join(","))
+            }
+            KeyCode::Char('r') => self.refresh().await,
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            _ => {}
+        }
+    }
+
+    async fn move_selection(&mut self, delta: isize) {
+        if self.games.is_empty() {
+            return;
+        }
+        let current = self.list.selected().unwrap_or(0) as isize;
+        let next = (current + delta).clamp(0, self.games.len() as isize - 1) as usize;
+        if Some(next) != self.list.selected() {
+            self.list.select(Some(next));
+            self.scroll = 0;
+            self.load_detail(false).await;
+        }
+    }
+
+    async fn select_tab(&mut self, tab: Tab) {
+        self.tab = tab;
+        self.scroll = 0;
+        self.load_detail(false).await;
+    }
+
+    async fn change_date(&mut self, days: i64) {
+        self.options.date += chrono::Duration::days(days);
+        self.list.select(None);
+        self.refresh().await;
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [left, right] =
+            Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .areas(main);
+        let [tabs_area, detail_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(right);
+
+        let items: Vec<ListItem> = self
+            .games
+            .iter()
+            .map(|game| {
+                let marker = if self.config.is_favorite(game) {
+                    "*"
+                } else {
+                    " "
+                };
+                ListItem::new(format!("{} {}", marker, game.title))
+            })
+            .collect();
+        let mut title = format!(" {} ", self.options.date.format("%a %Y-%m-%d"));
+        if !self.options.teams.is_empty() {
+            title.push_str(&format!("[{}] ", self.options.teams.join(",")));
+        }
+        let list = List::new(items)
+            .block(Block::bordered().title(title))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, left, &mut self.list);
+
+        let tabs = Tabs::new(Tab::ALL.iter().map(|t| t.title()))
+            .select(self.tab.index())
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(Block::bordered());
+        frame.render_widget(tabs, tabs_area);
+
+        let detail_title = self
+            .selected()
+            .map(|game| format!(" {} ", game.id))
+            .unwrap_or_default();
+        let detail = Paragraph::new(self.detail_text())
+            .block(Block::bordered().title(detail_title))
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0));
+        frame.render_widget(detail, detail_area);
+
+        let footer_text = match &self.filter_input {
+            Some(input) => format!("Teams (comma separated, Enter to apply): {}", input),
+            None => format!(
+                "q quit  ↑↓ game  Tab/1-3 pane  ←→ date  t today  / teams  r refresh  PgUp/PgDn scroll | {}",
+                self.status
+            ),
+        };
+        frame.render_widget(Paragraph::new(footer_text), footer);
+    }
+
+    fn detail_text(&self) -> String {
+        let Some(game) = self.selected() else {
+            return "No games".to_string();
+        };
+        let feed = self
+            .feed
+            .as_ref()
+            .filter(|(pk, _)| *pk == game.game_pk)
+            .map(|(_, feed)| feed);
+
+        match self.tab {
+            Tab::Linescore => match feed {
+                Some(feed) => format!("{}\n{}", feed.situation(), feed.linescore_table()),
+                None => match &game.linescore {
+                    Some(linescore) => linescore.to_string(),
+                    None => game.title.clone(),
+                },
+            },
+            Tab::Boxscore => match &self.boxscore {
+                Some((pk, boxscore)) if *pk == game.game_pk => boxscore.to_string(),
+                _ => "Loading...".to_string(),
+            },
+            Tab::Plays => match feed {
+                Some(feed) => {
+                    let plays = feed.plays(&PlayFilter::default());
+                    if plays.is_empty() {
+                        return "No plays yet".to_string();
+                    }
+                    plays
+                        .iter()
+                        .rev()
+                        .map(|play| {
+                            format!(
+                                "{} {} | {} | {}-{}\n  {}\n",
+                                play.half,
+                                play.inning,
+                                play.event,
+                                play.away_score,
+                                play.home_score,
+                                play.description
+                            )
+                        })
+                        .collect()
+                }
+                None => "Loading...".to_string(),
+            },
+        }
+    }
+}
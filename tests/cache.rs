@@ -0,0 +1,227 @@
+mod common;
+
+use std::path::PathBuf;
+
+use baseball_streams::client::fixture_path;
+use baseball_streams::{CacheMode, Client, ClientOptions, GameState};
+use chrono_tz::America::New_York;
+use common::{MockStatsApi, RunningMock, schedule_path};
+
+const FEED_PATH: &str = "/v1.1/game/777001/feed/live";
+
+fn client(mock: &RunningMock, cache: CacheMode) -> Client {
+    Client::new(ClientOptions {
+        statsapi_url: mock.url.clone(),
+        cache,
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// A fresh cache directory for one test.
+fn cache_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "baseball-streams-cache-{}-{}",
+        test,
+        std::process::id()
+    ));
+    baseball_streams::cache::clear(&dir).unwrap();
+    dir
+}
+
+#[tokio::test]
+async fn finished_schedule_is_fetched_once() {
+    let path = schedule_path("2024-07-07");
+    let mock = MockStatsApi::new()
+        .with_fixture(&path, "schedule_postponed.json")
+        .start()
+        .await;
+    let client = client(&mock, CacheMode::Memory);
+
+    for _ in 0..3 {
+        let games = client
+            .get_schedule("2024-07-07", &GameState::ALL, New_York)
+            .await
+            .unwrap();
+        assert_eq!(games.len(), 1);
+    }
+
+    assert_eq!(mock.requests(), [path]);
+}
+
+#[tokio::test]
+async fn without_a_cache_every_call_goes_upstream() {
+    let mock = MockStatsApi::new()
+        .with_fixture(&schedule_path("2024-07-07"), "schedule_postponed.json")
+        .start()
+        .await;
+    let client = client(&mock, CacheMode::Off);
+
+    for _ in 0..2 {
+        client
+            .get_schedule("2024-07-07", &GameState::ALL, New_York)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(mock.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn disk_cache_is_shared_between_runs() {
+    let dir = cache_dir("shared");
+    let mock = MockStatsApi::new()
+        .with_fixture(&schedule_path("2024-07-05"), "schedule_doubleheader.json")
+        .with_fixture(&schedule_path("2024-07-07"), "schedule_postponed.json")
+        .start()
+        .await;
+
+    for _ in 0..2 {
+        let client = client(&mock, CacheMode::Disk(dir.clone()));
+        client
+            .get_schedule("2024-07-07", &GameState::ALL, New_York)
+            .await
+            .unwrap();
+        client
+            .get_schedule("2024-07-05", &GameState::ALL, New_York)
+            .await
+            .unwrap();
+    }
+
+    // The second run reads both from disk: the finished day for good, the
+    // day with a live game because it is only seconds old.
+    assert_eq!(mock.requests().len(), 2);
+
+    baseball_streams::cache::clear(&dir).unwrap();
+    assert!(!dir.exists());
+}
+
+#[tokio::test]
+async fn stale_response_is_revalidated_with_its_etag() {
+    let dir = cache_dir("etag");
+    let mock = MockStatsApi::new()
+        .with_fixture(FEED_PATH, "feed.json")
+        .with_etag(FEED_PATH, "\"v1\"")
+        .start()
+        .await;
+
+    let first = client(&mock, CacheMode::Disk(dir.clone()))
+        .get_live_feed(777001)
+        .await
+        .unwrap();
+
+    // Age the saved response past its expiry.
+    let path = fixture_path(&dir, &format!("{}{}", mock.url, FEED_PATH));
+    let mut entry: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    entry["expires_at"] = "2000-01-01T00:00:00Z".into();
+    std::fs::write(&path, entry.to_string()).unwrap();
+
+    let second = client(&mock, CacheMode::Disk(dir.clone()))
+        .get_live_feed(777001)
+        .await
+        .unwrap();
+
+    assert_eq!(mock.statuses(), [200, 304]);
+    assert_eq!(second.situation().batter, first.situation().batter);
+
+    baseball_streams::cache::clear(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn hits_within_the_ttl_do_not_extend_it() {
+    let dir = cache_dir("hits");
+    let mock = MockStatsApi::new()
+        .with_fixture(FEED_PATH, "feed.json")
+        .start()
+        .await;
+    let client = client(&mock, CacheMode::Disk(dir.clone()));
+    let path = fixture_path(&dir, &format!("{}{}", mock.url, FEED_PATH));
+    let expires_at = || {
+        let entry: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        entry["expires_at"].clone()
+    };
+
+    client.get_live_feed(777001).await.unwrap();
+    let fetched = expires_at();
+
+    for _ in 0..3 {
+        client.get_live_feed(777001).await.unwrap();
+    }
+
+    // Otherwise a client polling faster than the TTL would never refresh.
+    assert_eq!(mock.requests(), [FEED_PATH]);
+    assert_eq!(expires_at(), fetched);
+
+    baseball_streams::cache::clear(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn final_boxscore_is_kept_for_good() {
+    const BOXSCORE_PATH: &str = "/v1/game/777001/boxscore";
+    let dir = cache_dir("boxscore");
+    let mock = MockStatsApi::new()
+        .with_fixture(BOXSCORE_PATH, "boxscore.json")
+        .start()
+        .await;
+    let expires_at = |path: &str| {
+        let path = fixture_path(&dir, &format!("{}{}", mock.url, path));
+        let entry: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        entry["expires_at"]
+            .as_str()
+            .unwrap()
+            .parse::<chrono::DateTime<chrono::Utc>>()
+            .unwrap()
+    };
+
+    client(&mock, CacheMode::Disk(dir.clone()))
+        .get_boxscore(777001, GameState::Live)
+        .await
+        .unwrap();
+    assert!(expires_at(BOXSCORE_PATH) < chrono::Utc::now() + chrono::Duration::minutes(1));
+
+    baseball_streams::cache::clear(&dir).unwrap();
+    client(&mock, CacheMode::Disk(dir.clone()))
+        .get_boxscore(777001, GameState::Final)
+        .await
+        .unwrap();
+    assert!(expires_at(BOXSCORE_PATH) > chrono::Utc::now() + chrono::Duration::days(1));
+
+    baseball_streams::cache::clear(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn memory_cache_drops_the_oldest_responses_past_its_limit() {
+    let dates: Vec<String> = (0..257)
+        .map(|day| {
+            (chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap() + chrono::Duration::days(day))
+                .to_string()
+        })
+        .collect();
+    let mut mock = MockStatsApi::new();
+    for date in &dates {
+        mock = mock.with_response(&schedule_path(date), 200, r#"{"dates": []}"#);
+    }
+    let mock = mock.start().await;
+    let client = client(&mock, CacheMode::Memory);
+
+    for date in &dates {
+        client
+            .get_schedule(date, &GameState::ALL, New_York)
+            .await
+            .unwrap();
+    }
+    // The most recent response is still held; the first one made way.
+    for date in [&dates[256], &dates[0]] {
+        client
+            .get_schedule(date, &GameState::ALL, New_York)
+            .await
+            .unwrap();
+    }
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 258);
+    assert_eq!(requests[257], schedule_path(&dates[0]));
+}
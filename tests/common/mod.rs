@@ -0,0 +1,298 @@
+//! Local stand-ins for statsapi.mlb.com, serving fixture JSON, and for
+//! webhook endpoints.
+
+// Each test crate uses a different subset of these helpers.
+#![allow(dead_code)]
+
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::sync::{Arc, Mutex};
+
+use baseball_streams::{Client, ClientOptions};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Reads `tests/fixtures/<name>`.
+pub fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("reading {}: {}", path, err))
+}
+
+/// Path and query of the schedule request for `date`.
+pub fn schedule_path(date: &str) -> String {
+    format!(
+        "/v1/schedule?sportId=1&hydrate=team,linescore&date={}",
+        date
+    )
+}
+
+/// Answers GET requests by exact path and query; anything else is a 404.
+#[derive(Default)]
+pub struct MockStatsApi {
+    routes: HashMap<String, Route>,
+}
+
+#[derive(Clone)]
+struct Route {
+    status: u16,
+    body: String,
+    etag: Option<String>,
+}
+
+impl MockStatsApi {
+    pub fn new() -> Self {
+        MockStatsApi::default()
+    }
+
+    /// Serves the fixture file `name` at `path`.
+    pub fn with_fixture(self, path: &str, name: &str) -> Self {
+        self.with_response(path, 200, &fixture(name))
+    }
+
+    /// Serves `body` with `status` at `path`.
+    pub fn with_response(mut self, path: &str, status: u16, body: &str) -> Self {
+        self.routes.insert(
+            path.to_string(),
+            Route {
+                status,
+                body: body.to_string(),
+                etag: None,
+            },
+        );
+        self
+    }
+
+    /// Sends `etag` with the response at `path`, and answers 304 Not
+    /// Modified to requests that present it.
+    pub fn with_etag(mut self, path: &str, etag: &str) -> Self {
+        self.routes.get_mut(path).expect("route exists").etag = Some(etag.to_string());
+        self
+    }
+
+    /// Starts listening on a free local port. The server lives until the
+    /// test's runtime shuts down.
+    pub async fn start(self) -> RunningMock {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let routes = Arc::new(self.routes);
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let log = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                let routes = routes.clone();
+                let log = log.clone();
+
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buf = [0; 4096];
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => request.extend_from_slice(&buf[..n]),
+                        }
+                    }
+
+                    let request = String::from_utf8_lossy(&request);
+                    let path = request
+                        .split_whitespace()
+                        .nth(1)
+                        .unwrap_or_default()
+                        .to_string();
+                    let if_none_match = request.lines().find_map(|line| {
+                        let (name, value) = line.split_once(':')?;
+                        name.eq_ignore_ascii_case("if-none-match")
+                            .then(|| value.trim().to_string())
+                    });
+
+                    let route = routes.get(&path).cloned().unwrap_or(Route {
+                        status: 404,
+                        body: r#"{"message":"not found"}"#.to_string(),
+                        etag: None,
+                    });
+                    let (status, body) = match &route.etag {
+                        Some(etag) if if_none_match.as_ref() == Some(etag) => (304, String::new()),
+                        _ => (route.status, route.body),
+                    };
+                    log.lock().unwrap().push((path, status));
+
+                    let etag = route
+                        .etag
+                        .map(|etag| format!("ETag: {}\r\n", etag))
+                        .unwrap_or_default();
+                    let response = format!(
+                        "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        etag,
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        RunningMock { url, requests }
+    }
+}
+
+/// A started [`MockStatsApi`].
+pub struct RunningMock {
+    pub url: String,
+    requests: Arc<Mutex<Vec<(String, u16)>>>,
+}
+
+impl RunningMock {
+    /// A client sending statsapi and streaming requests to the mock.
+    pub fn client(&self) -> Client {
+        Client::new(ClientOptions {
+            statsapi_url: self.url.clone(),
+            streams_url: self.url.clone(),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    /// Paths requested so far, in order.
+    pub fn requests(&self) -> Vec<String> {
+        let requests = self.requests.lock().unwrap();
+        requests.iter().map(|(path, _)| path.clone()).collect()
+    }
+
+    /// Status codes answered so far, in order.
+    pub fn statuses(&self) -> Vec<u16> {
+        let requests = self.requests.lock().unwrap();
+        requests.iter().map(|(_, status)| *status).collect()
+    }
+}
+
+/// Receives webhook POSTs, answering with the given statuses in turn and
+/// 200 once they run out.
+#[derive(Default)]
+pub struct MockWebhook {
+    statuses: VecDeque<u16>,
+    silent: bool,
+}
+
+/// One request received by a [`MockWebhook`].
+#[derive(Debug, Clone)]
+pub struct Delivery {
+    headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Delivery {
+    /// Value of the header `name`, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl MockWebhook {
+    pub fn new() -> Self {
+        MockWebhook::default()
+    }
+
+    /// Answers the first requests with `statuses`, in order.
+    pub fn with_statuses(mut self, statuses: &[u16]) -> Self {
+        self.statuses = statuses.iter().copied().collect();
+        self
+    }
+
+    /// Reads requests but never answers them.
+    pub fn silent(mut self) -> Self {
+        self.silent = true;
+        self
+    }
+
+    pub async fn start(self) -> RunningWebhook {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let statuses = Arc::new(Mutex::new(self.statuses));
+        let deliveries = Arc::new(Mutex::new(Vec::new()));
+        let silent = self.silent;
+
+        let log = deliveries.clone();
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                let statuses = statuses.clone();
+                let log = log.clone();
+
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buf = [0; 4096];
+                    let head_end = loop {
+                        if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                            break i + 4;
+                        }
+                        match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => request.extend_from_slice(&buf[..n]),
+                        }
+                    };
+
+                    let head = String::from_utf8_lossy(&request[..head_end]).to_string();
+                    let headers: Vec<(String, String)> = head
+                        .lines()
+                        .skip(1)
+                        .filter_map(|line| {
+                            let (name, value) = line.split_once(':')?;
+                            Some((name.trim().to_string(), value.trim().to_string()))
+                        })
+                        .collect();
+                    let length = headers
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                        .and_then(|(_, value)| value.parse().ok())
+                        .unwrap_or(0);
+                    while request.len() < head_end + length {
+                        match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => request.extend_from_slice(&buf[..n]),
+                        }
+                    }
+
+                    let body = String::from_utf8_lossy(&request[head_end..]).to_string();
+                    log.lock().unwrap().push(Delivery { headers, body });
+
+                    if silent {
+                        // Hold the connection open until the client gives up.
+                        let _ = socket.read(&mut buf).await;
+                        return;
+                    }
+
+                    let status = statuses.lock().unwrap().pop_front().unwrap_or(200);
+                    let response = format!(
+                        "HTTP/1.1 {} Mock\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                        status
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        RunningWebhook { url, deliveries }
+    }
+}
+
+/// A started [`MockWebhook`].
+pub struct RunningWebhook {
+    pub url: String,
+    deliveries: Arc<Mutex<Vec<Delivery>>>,
+}
+
+impl RunningWebhook {
+    /// Requests received so far, in order.
+    pub fn deliveries(&self) -> Vec<Delivery> {
+        self.deliveries.lock().unwrap().clone()
+    }
+}
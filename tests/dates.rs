@@ -0,0 +1,45 @@
+use baseball_streams::DateSpec;
+use chrono::NaiveDate;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 7, 4).unwrap()
+}
+
+fn resolve(spec: &str) -> NaiveDate {
+    spec.parse::<DateSpec>().unwrap().resolve(today())
+}
+
+#[test]
+fn keywords_and_offsets_resolve_against_today() {
+    assert_eq!(resolve("today"), today());
+    assert_eq!(resolve("Yesterday").to_string(), "2024-07-03");
+    assert_eq!(resolve("tomorrow").to_string(), "2024-07-05");
+    assert_eq!(resolve("+30").to_string(), "2024-08-03");
+    assert_eq!(resolve("-4").to_string(), "2024-06-30");
+    assert_eq!(resolve("2025-03-27").to_string(), "2025-03-27");
+}
+
+#[test]
+fn offsets_beyond_a_century_are_rejected() {
+    assert!("+36525".parse::<DateSpec>().is_ok());
+    for spec in [
+        "+36526",
+        "+100000000",
+        "-9223372036854775808",
+        "+99999999999999999999",
+    ] {
+        assert!(spec.parse::<DateSpec>().is_err(), "{} parsed", spec);
+    }
+}
+
+#[test]
+fn constructed_offsets_stop_at_the_ends_of_the_calendar() {
+    assert_eq!(
+        DateSpec::Relative(i64::MAX).resolve(today()),
+        NaiveDate::MAX
+    );
+    assert_eq!(
+        DateSpec::Relative(i64::MIN).resolve(today()),
+        NaiveDate::MIN
+    );
+}
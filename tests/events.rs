@@ -0,0 +1,94 @@
+mod common;
+
+use baseball_streams::{EventDetector, EventKind, GameEvent, Snapshot};
+
+/// Recorded refreshes of two games, in order: NYY @ BOS from the 1st inning
+/// to a walk-off in the 10th, and LAD @ SF joining partway through.
+fn frames() -> Vec<Vec<Snapshot>> {
+    serde_json::from_str(&common::fixture("snapshots.json")).unwrap()
+}
+
+/// Feeds every frame to `detector` and returns the events of each.
+fn replay(mut detector: EventDetector) -> Vec<Vec<EventKind>> {
+    frames()
+        .into_iter()
+        .map(|frame| {
+            detector
+                .observe(frame)
+                .into_iter()
+                .map(|event| event.kind)
+                .collect()
+        })
+        .collect()
+}
+
+fn run(team: &str, runs: u32) -> EventKind {
+    EventKind::RunScored {
+        team: team.to_string(),
+        runs,
+    }
+}
+
+fn lead(team: &str) -> EventKind {
+    EventKind::LeadChange {
+        team: team.to_string(),
+    }
+}
+
+#[test]
+fn detects_events_between_snapshots() {
+    assert_eq!(
+        replay(EventDetector::new(Vec::new())),
+        [
+            // The first snapshot only sets the baseline, runs and all.
+            vec![],
+            vec![run("BOS", 2), lead("BOS")],
+            // LAD @ SF first appears already 3-0, which is not reported.
+            vec![EventKind::NoHitter {
+                team: "NYY".to_string()
+            }],
+            // A tying run is not a lead change.
+            vec![run("NYY", 1), EventKind::ExtraInnings],
+            vec![run("BOS", 1), lead("BOS"), EventKind::Final],
+        ]
+    );
+}
+
+#[test]
+fn favorites_only_filter_runs_scored() {
+    assert_eq!(
+        replay(EventDetector::new(vec!["Yankees".to_string()])),
+        [
+            vec![],
+            vec![lead("BOS")],
+            vec![EventKind::NoHitter {
+                team: "NYY".to_string()
+            }],
+            vec![run("NYY", 1), EventKind::ExtraInnings],
+            vec![lead("BOS"), EventKind::Final],
+        ]
+    );
+}
+
+#[test]
+fn events_carry_the_current_score_and_inning() {
+    let mut detector = EventDetector::new(Vec::new());
+    let events: Vec<GameEvent> = frames()
+        .into_iter()
+        .flat_map(|frame| detector.observe(frame))
+        .collect();
+
+    let walk_off = &events[events.len() - 3];
+    assert_eq!(walk_off.game_pk, 745001);
+    assert_eq!(
+        (walk_off.away.as_str(), walk_off.home.as_str()),
+        ("NYY", "BOS")
+    );
+    assert_eq!((walk_off.away_score, walk_off.home_score), (2, 3));
+    assert_eq!(walk_off.inning, Some(10));
+    assert_eq!(
+        walk_off.to_string(),
+        "BOS scored 1 run: NYY 2, BOS 3 (Bottom 10)"
+    );
+    assert_eq!(events.last().unwrap().to_string(), "Final: NYY 2, BOS 3");
+}
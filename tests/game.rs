@@ -0,0 +1,183 @@
+mod common;
+
+use baseball_streams::{Client, ClientOptions, Error, Fixtures, GameState, PlayFilter};
+use common::MockStatsApi;
+
+const FEED_PATH: &str = "/v1.1/game/777001/feed/live";
+const BOXSCORE_PATH: &str = "/v1/game/777001/boxscore";
+
+#[tokio::test]
+async fn live_feed_situation() {
+    let mock = MockStatsApi::new()
+        .with_fixture(FEED_PATH, "feed.json")
+        .start()
+        .await;
+
+    let feed = mock.client().get_live_feed(777001).await.unwrap();
+    let situation = feed.situation();
+
+    assert_eq!(situation.game_pk, 777001);
+    assert_eq!(
+        (situation.away.as_str(), situation.home.as_str()),
+        ("NYY", "BOS")
+    );
+    assert_eq!(situation.inning, Some(1));
+    assert_eq!(
+        (situation.balls, situation.strikes, situation.outs),
+        (2, 1, 0)
+    );
+    assert_eq!(situation.runners.first.as_deref(), Some("Aaron Judge"));
+    assert_eq!(situation.runners.second, None);
+    assert_eq!(situation.batter.as_deref(), Some("Juan Soto"));
+    assert_eq!(situation.pitcher.as_deref(), Some("Brayan Bello"));
+    assert!(!feed.is_final());
+}
+
+#[tokio::test]
+async fn live_feed_plays_skip_the_plate_appearance_in_progress() {
+    let mock = MockStatsApi::new()
+        .with_fixture(FEED_PATH, "feed.json")
+        .start()
+        .await;
+
+    let feed = mock.client().get_live_feed(777001).await.unwrap();
+    let plays = feed.plays(&PlayFilter::default());
+
+    assert_eq!(plays.len(), 1);
+    assert_eq!(plays[0].event, "Single");
+    assert_eq!(plays[0].batter, "Aaron Judge");
+
+    let after = feed.plays(&PlayFilter {
+        after: Some(0),
+        ..Default::default()
+    });
+    assert!(after.is_empty());
+}
+
+#[tokio::test]
+async fn live_feed_plays_by_batting_team() {
+    let mock = MockStatsApi::new()
+        .with_fixture(FEED_PATH, "feed.json")
+        .start()
+        .await;
+
+    let feed = mock.client().get_live_feed(777001).await.unwrap();
+    let batting = |team: &str| {
+        feed.plays(&PlayFilter {
+            teams: vec![team.to_string()],
+            ..Default::default()
+        })
+        .len()
+    };
+
+    // Teams are named the same ways as in `--team` on listings.
+    assert_eq!(batting("yankees"), 1);
+    assert_eq!(batting("NYY"), 1);
+    assert_eq!(batting("BOS"), 0);
+}
+
+#[tokio::test]
+async fn live_feed_pitches() {
+    let mock = MockStatsApi::new()
+        .with_fixture(FEED_PATH, "feed.json")
+        .start()
+        .await;
+
+    let feed = mock.client().get_live_feed(777001).await.unwrap();
+    let pitches = feed.plate_appearance(Some(0)).unwrap().pitches();
+
+    // The pickoff attempt between pitches is not a pitch.
+    assert_eq!(pitches.len(), 5);
+    assert_eq!(pitches[0].pitch_code.as_deref(), Some("FF"));
+    assert_eq!(pitches[4].call, "In play, no out");
+}
+
+#[tokio::test]
+async fn strike_zone_plot_marks_any_pitch_number() {
+    let mock = MockStatsApi::new()
+        .with_fixture(FEED_PATH, "feed.json")
+        .start()
+        .await;
+
+    let feed = mock.client().get_live_feed(777001).await.unwrap();
+    let mut pitches = feed.plate_appearance(Some(0)).unwrap().pitches();
+    pitches[0].number = 0;
+    pitches[1].number = 12;
+
+    let plot = baseball_streams::strike_zone_plot(&pitches);
+
+    assert!(plot.contains('0'));
+    assert!(plot.contains('c'));
+}
+
+#[tokio::test]
+async fn boxscore_lines() {
+    let mock = MockStatsApi::new()
+        .with_fixture(BOXSCORE_PATH, "boxscore.json")
+        .start()
+        .await;
+
+    let boxscore = mock
+        .client()
+        .get_boxscore(777001, GameState::Live)
+        .await
+        .unwrap();
+
+    assert_eq!(boxscore.away.team, "NYY");
+    // The pitcher is listed among the batters but never came to the plate.
+    assert_eq!(boxscore.away.batters.len(), 2);
+    assert_eq!(boxscore.away.batters[0].name, "Aaron Judge");
+    assert!(boxscore.away.batters[1].substitute);
+    assert_eq!(boxscore.away.pitchers.len(), 1);
+    assert_eq!(boxscore.away.bench, ["Bench Guy"]);
+    assert!(boxscore.home.batters.is_empty());
+    assert_eq!(boxscore.info.umpires.len(), 1);
+}
+
+#[tokio::test]
+async fn unknown_game_is_not_found() {
+    let mock = MockStatsApi::new().start().await;
+
+    let err = mock.client().get_live_feed(1).await.unwrap_err();
+
+    assert!(matches!(err, Error::HttpStatus { status, .. } if status.as_u16() == 404));
+}
+
+#[tokio::test]
+async fn recorded_responses_replay_without_the_server() {
+    let mock = MockStatsApi::new()
+        .with_fixture(BOXSCORE_PATH, "boxscore.json")
+        .start()
+        .await;
+    let dir = std::env::temp_dir().join(format!("baseball-streams-replay-{}", std::process::id()));
+
+    let options = |fixtures| ClientOptions {
+        statsapi_url: mock.url.clone(),
+        fixtures: Some(fixtures),
+        ..Default::default()
+    };
+
+    let recording = Client::new(options(Fixtures::Record(dir.clone()))).unwrap();
+    let recorded = recording
+        .get_boxscore(777001, GameState::Live)
+        .await
+        .unwrap();
+    assert_eq!(mock.requests(), [BOXSCORE_PATH]);
+
+    let replaying = Client::new(options(Fixtures::Replay(dir.clone()))).unwrap();
+    let replayed = replaying
+        .get_boxscore(777001, GameState::Live)
+        .await
+        .unwrap();
+    assert_eq!(mock.requests().len(), 1);
+    assert_eq!(replayed.away.batters.len(), recorded.away.batters.len());
+
+    // Anything not recorded fails instead of reaching the network.
+    assert!(matches!(
+        replaying.get_live_feed(777001).await,
+        Err(Error::Io(_))
+    ));
+    assert_eq!(mock.requests().len(), 1);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,263 @@
+mod common;
+
+use baseball_streams::linescore::InningRuns;
+use baseball_streams::{Error, Game, GameState};
+use chrono_tz::America::New_York;
+use common::{MockStatsApi, schedule_path};
+
+async fn schedule(fixture: &str, date: &str, states: &[GameState]) -> Vec<Game> {
+    let mock = MockStatsApi::new()
+        .with_fixture(&schedule_path(date), fixture)
+        .start()
+        .await;
+
+    mock.client()
+        .get_schedule(date, states, New_York)
+        .await
+        .unwrap()
+}
+
+fn titles(games: &[Game]) -> Vec<&str> {
+    games.iter().map(|game| game.title.as_str()).collect()
+}
+
+#[tokio::test]
+async fn lists_games_in_every_state() {
+    let games = schedule("schedule.json", "2024-07-04", &GameState::ALL).await;
+
+    assert_eq!(
+        titles(&games),
+        [
+            "NYY (5) vs BOS (3) | Final | 1:05 PM EDT",
+            "LAD (2) vs SF (1) | Bottom of 5th | 4:10 PM EDT",
+            "CHC vs STL | 7:15 PM EDT",
+            "HOU (4) vs TEX (3) | F/10 | 6:00 PM EDT",
+        ]
+    );
+
+    let live = &games[1];
+    assert_eq!(live.game_pk, 745002);
+    assert_eq!(live.date, "2024-07-04");
+    assert_eq!(live.state, GameState::Live);
+    assert_eq!(live.inning, Some(5));
+    assert_eq!(live.inning_half.as_deref(), Some("Bottom"));
+    assert_eq!(live.away.abbreviation, "LAD");
+    assert_eq!(live.away.team_name, "Dodgers");
+    assert_eq!(live.home.score, 1);
+    assert_eq!(live.id, "San Francisco Giants vs Los Angeles Dodgers");
+    assert!(!live.double_header);
+}
+
+#[tokio::test]
+async fn filters_by_state() {
+    let games = schedule("schedule.json", "2024-07-04", &[GameState::Final]).await;
+    assert_eq!(
+        games.iter().map(|game| game.game_pk).collect::<Vec<_>>(),
+        [745001, 745004]
+    );
+
+    let games = schedule("schedule.json", "2024-07-04", &[GameState::Preview]).await;
+    assert_eq!(titles(&games), ["CHC vs STL | 7:15 PM EDT"]);
+}
+
+#[tokio::test]
+async fn skips_malformed_and_incomplete_games() {
+    let games = schedule("schedule.json", "2024-07-04", &GameState::ALL).await;
+
+    assert!(!games.iter().any(|game| game.game_pk == 745005));
+    assert!(!games.iter().any(|game| game.game_pk == 745006));
+}
+
+#[tokio::test]
+async fn builds_linescores() {
+    let games = schedule("schedule.json", "2024-07-04", &GameState::ALL).await;
+
+    let final_game = games[0].linescore.as_ref().unwrap();
+    assert_eq!(final_game.innings.len(), 9);
+    assert_eq!(final_game.away.runs, 5);
+    assert_eq!(final_game.home.runs, 3);
+
+    // Innings not reached yet are padded out to nine.
+    let live = games[1].linescore.as_ref().unwrap();
+    assert_eq!(live.innings.len(), 9);
+    assert_eq!(live.innings[4].away, InningRuns::Runs(1));
+    assert_eq!(live.innings[4].home, InningRuns::Pending);
+    assert_eq!(live.innings[8].away, InningRuns::Pending);
+
+    let extras = games[3].linescore.as_ref().unwrap();
+    assert_eq!(extras.innings.len(), 10);
+
+    assert!(games[2].linescore.is_none());
+}
+
+#[tokio::test]
+async fn postponed_game_shows_its_status() {
+    let games = schedule("schedule_postponed.json", "2024-07-07", &GameState::ALL).await;
+
+    assert_eq!(
+        titles(&games),
+        ["SEA (0) vs OAK (0) | Postponed | 4:10 PM EDT"]
+    );
+    assert_eq!(games[0].state, GameState::Final);
+    assert!(games[0].linescore.is_none());
+    assert_eq!(games[0].inning, None);
+}
+
+#[tokio::test]
+async fn suspended_game_shows_its_status_instead_of_the_inning() {
+    let games = schedule("schedule_suspended.json", "2024-07-08", &GameState::ALL).await;
+
+    assert_eq!(
+        titles(&games),
+        ["NYM (2) vs PHI (2) | Suspended: Rain | 7:05 PM EDT"]
+    );
+    assert_eq!(games[0].inning, Some(6));
+
+    // Half-innings never played are marked rather than left pending.
+    let linescore = games[0].linescore.as_ref().unwrap();
+    assert_eq!(linescore.innings.len(), 6);
+    assert_eq!(linescore.innings[5].home, InningRuns::NotPlayed);
+}
+
+#[tokio::test]
+async fn live_game_without_linescore() {
+    let games = schedule(
+        "schedule_missing_linescore.json",
+        "2024-07-09",
+        &GameState::ALL,
+    )
+    .await;
+
+    assert_eq!(
+        titles(&games),
+        ["CHC (1) vs STL (0) | Top of N/A | 7:10 PM EDT"]
+    );
+    assert!(games[0].linescore.is_none());
+    assert_eq!(games[0].inning, None);
+    assert_eq!(games[0].inning_half, None);
+}
+
+#[tokio::test]
+async fn doubleheader_games_are_numbered() {
+    let games = schedule("schedule_doubleheader.json", "2024-07-05", &GameState::ALL).await;
+
+    assert_eq!(
+        titles(&games),
+        [
+            "NYM (3) vs PHI (6) | Game 1 | Final | 1:05 PM EDT",
+            "NYM (0) vs PHI (1) | Game 2 | Top of 2nd | 7:05 PM EDT",
+        ]
+    );
+    assert!(games.iter().all(|game| game.double_header));
+    assert_eq!(games[0].game_number, 1);
+    assert_eq!(games[1].game_number, 2);
+
+    // The home team did not need the bottom of the 9th.
+    let linescore = games[0].linescore.as_ref().unwrap();
+    assert_eq!(linescore.innings[8].home, InningRuns::NotPlayed);
+}
+
+#[tokio::test]
+async fn empty_date_has_no_games() {
+    let games = schedule("schedule_empty.json", "2024-12-25", &GameState::ALL).await;
+
+    assert!(games.is_empty());
+}
+
+#[tokio::test]
+async fn date_range_is_requested_once_and_kept_in_order() {
+    let path =
+        "/v1/schedule?sportId=1&hydrate=team,linescore&startDate=2024-07-04&endDate=2024-07-05";
+    let mock = MockStatsApi::new()
+        .with_fixture(path, "schedule_range.json")
+        .start()
+        .await;
+
+    let games = mock
+        .client()
+        .get_schedule_range("2024-07-04", "2024-07-05", &GameState::ALL, New_York)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        games
+            .iter()
+            .map(|game| game.date.as_str())
+            .collect::<Vec<_>>(),
+        ["2024-07-04", "2024-07-05"]
+    );
+    assert_eq!(mock.requests(), [path]);
+}
+
+#[tokio::test]
+async fn start_times_follow_the_time_zone() {
+    let mock = MockStatsApi::new()
+        .with_fixture(&schedule_path("2024-07-04"), "schedule.json")
+        .start()
+        .await;
+
+    let games = mock
+        .client()
+        .get_schedule(
+            "2024-07-04",
+            &[GameState::Preview],
+            chrono_tz::America::Los_Angeles,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(titles(&games), ["CHC vs STL | 4:15 PM PDT"]);
+}
+
+#[tokio::test]
+async fn http_errors_are_reported_with_their_status() {
+    let mock = MockStatsApi::new()
+        .with_response(&schedule_path("2024-07-04"), 503, "{}")
+        .start()
+        .await;
+
+    let err = mock
+        .client()
+        .get_schedule("2024-07-04", &GameState::ALL, New_York)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::HttpStatus { status, .. } if status.as_u16() == 503));
+    assert_eq!(err.exit_code(), 4);
+}
+
+#[tokio::test]
+async fn unexpected_shape_names_the_field() {
+    let mock = MockStatsApi::new()
+        .with_response(
+            &schedule_path("2024-07-04"),
+            200,
+            r#"{"dates": [{"games": []}]}"#,
+        )
+        .start()
+        .await;
+
+    let err = mock
+        .client()
+        .get_schedule("2024-07-04", &GameState::ALL, New_York)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(&err, Error::Shape { path, .. } if path == "dates[0]"));
+}
+
+#[tokio::test]
+async fn invalid_json_is_a_decode_error() {
+    let mock = MockStatsApi::new()
+        .with_response(&schedule_path("2024-07-04"), 200, "<html>")
+        .start()
+        .await;
+
+    let err = mock
+        .client()
+        .get_schedule("2024-07-04", &GameState::ALL, New_York)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::Decode { .. }));
+}
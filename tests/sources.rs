@@ -0,0 +1,121 @@
+mod common;
+
+use baseball_streams::{Error, Game, GameState};
+use chrono_tz::America::New_York;
+use common::{MockStatsApi, RunningMock, schedule_path};
+
+const MATCHES_PATH: &str = "/matches/baseball";
+
+/// Game 1 starts at 1:05 PM EDT, Game 2 at 7:05 PM EDT.
+const GAME_1_MS: i64 = 1720199100000;
+const GAME_2_MS: i64 = 1720220700000;
+
+/// A streaming site listing of the doubleheader, one match per source id.
+fn listing(dates: &[Option<i64>]) -> String {
+    let matches: Vec<_> = dates
+        .iter()
+        .enumerate()
+        .map(|(i, date)| {
+            serde_json::json!({
+                "title": "Philadelphia Phillies vs New York Mets",
+                "date": date,
+                "sources": [{ "id": format!("source-{}", i), "source": "alpha" }],
+            })
+        })
+        .collect();
+    serde_json::Value::from(matches).to_string()
+}
+
+async fn doubleheader(dates: &[Option<i64>]) -> (RunningMock, Vec<Game>) {
+    let mock = MockStatsApi::new()
+        .with_fixture(&schedule_path("2024-07-05"), "schedule_doubleheader.json")
+        .with_response(MATCHES_PATH, 200, &listing(dates))
+        .start()
+        .await;
+    let games = mock
+        .client()
+        .get_schedule("2024-07-05", &GameState::ALL, New_York)
+        .await
+        .unwrap();
+    (mock, games)
+}
+
+/// The source id matched to `game`, or `None` for no match.
+async fn source_id(mock: &RunningMock, game: &Game) -> Option<String> {
+    match mock.client().get_sources(game).await {
+        Ok(sources) => Some(sources[0]["id"].as_str().unwrap().to_string()),
+        Err(Error::NoMatch(_)) => None,
+        Err(err) => panic!("unexpected error: {}", err),
+    }
+}
+
+#[tokio::test]
+async fn doubleheader_games_match_by_start_time() {
+    // Listed in the opposite order to the schedule.
+    let (mock, games) = doubleheader(&[Some(GAME_2_MS), Some(GAME_1_MS)]).await;
+
+    assert_eq!(
+        source_id(&mock, &games[0]).await.as_deref(),
+        Some("source-1")
+    );
+    assert_eq!(
+        source_id(&mock, &games[1]).await.as_deref(),
+        Some("source-0")
+    );
+}
+
+#[tokio::test]
+async fn lone_listing_only_matches_the_game_it_starts_with() {
+    let (mock, games) = doubleheader(&[Some(GAME_1_MS + 10 * 60 * 1000)]).await;
+
+    assert_eq!(
+        source_id(&mock, &games[0]).await.as_deref(),
+        Some("source-0")
+    );
+    assert_eq!(source_id(&mock, &games[1]).await, None);
+}
+
+#[tokio::test]
+async fn without_start_times_the_game_number_decides() {
+    let (mock, games) = doubleheader(&[None, None]).await;
+
+    assert_eq!(
+        source_id(&mock, &games[0]).await.as_deref(),
+        Some("source-0")
+    );
+    assert_eq!(
+        source_id(&mock, &games[1]).await.as_deref(),
+        Some("source-1")
+    );
+
+    let (mock, games) = doubleheader(&[None]).await;
+    assert_eq!(source_id(&mock, &games[1]).await, None);
+}
+
+#[tokio::test]
+async fn single_game_matches_its_title_whatever_the_listed_time() {
+    let mock = MockStatsApi::new()
+        .with_fixture(&schedule_path("2024-07-04"), "schedule.json")
+        .start()
+        .await;
+    let games = mock
+        .client()
+        .get_schedule("2024-07-04", &GameState::ALL, New_York)
+        .await
+        .unwrap();
+    let game = games.iter().find(|game| game.game_pk == 745002).unwrap();
+
+    // Listed five hours off, as after a long rain delay.
+    let start_ms = game.start_time.unwrap().timestamp_millis();
+    let listing = serde_json::json!([{
+        "title": game.id,
+        "date": start_ms + 5 * 60 * 60 * 1000,
+        "sources": [{ "id": "source-0", "source": "alpha" }],
+    }]);
+    let mock = MockStatsApi::new()
+        .with_response(MATCHES_PATH, 200, &listing.to_string())
+        .start()
+        .await;
+
+    assert_eq!(source_id(&mock, game).await.as_deref(), Some("source-0"));
+}
@@ -0,0 +1,234 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use baseball_streams::Error;
+use baseball_streams::events::{
+    Dispatcher, EventKind, EventSink, GameEvent, SIGNATURE_HEADER, WebhookConfig, WebhookFormat,
+    WebhookSink,
+};
+use common::{MockWebhook, RunningWebhook};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+fn run_scored() -> GameEvent {
+    GameEvent {
+        game_pk: 745002,
+        away: "LAD".to_string(),
+        home: "SF".to_string(),
+        away_score: 2,
+        home_score: 1,
+        inning: Some(5),
+        inning_half: Some("Top".to_string()),
+        kind: EventKind::RunScored {
+            team: "LAD".to_string(),
+            runs: 2,
+        },
+    }
+}
+
+fn sink(webhook: &RunningWebhook, config: WebhookConfig) -> WebhookSink {
+    WebhookSink::new(WebhookConfig {
+        url: webhook.url.clone(),
+        ..config
+    })
+    .unwrap()
+}
+
+#[tokio::test]
+async fn json_body_is_signed_with_the_secret() {
+    let webhook = MockWebhook::new().start().await;
+    let sink = sink(
+        &webhook,
+        WebhookConfig {
+            secret: Some("s3cret".to_string()),
+            ..Default::default()
+        },
+    );
+
+    sink.send(&run_scored()).await.unwrap();
+
+    let deliveries = webhook.deliveries();
+    assert_eq!(deliveries.len(), 1);
+    let body: serde_json::Value = serde_json::from_str(&deliveries[0].body).unwrap();
+    assert_eq!(body["type"], "run_scored");
+    assert_eq!(body["team"], "LAD");
+    assert_eq!(body["away_score"], 2);
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(b"s3cret").unwrap();
+    mac.update(deliveries[0].body.as_bytes());
+    let expected = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+    assert_eq!(
+        deliveries[0].header(SIGNATURE_HEADER),
+        Some(expected.as_str())
+    );
+}
+
+#[tokio::test]
+async fn unsigned_without_a_secret() {
+    let webhook = MockWebhook::new().start().await;
+
+    sink(&webhook, WebhookConfig::default())
+        .send(&run_scored())
+        .await
+        .unwrap();
+
+    assert_eq!(webhook.deliveries()[0].header(SIGNATURE_HEADER), None);
+}
+
+#[tokio::test]
+async fn slack_and_discord_bodies() {
+    let webhook = MockWebhook::new().start().await;
+
+    for format in [WebhookFormat::Slack, WebhookFormat::Discord] {
+        sink(
+            &webhook,
+            WebhookConfig {
+                format,
+                ..Default::default()
+            },
+        )
+        .send(&run_scored())
+        .await
+        .unwrap();
+    }
+    sink(
+        &webhook,
+        WebhookConfig {
+            format: WebhookFormat::Slack,
+            template: Some("{away} {away_score}-{home_score} {home}, inning {inning}".to_string()),
+            ..Default::default()
+        },
+    )
+    .send(&run_scored())
+    .await
+    .unwrap();
+
+    let bodies: Vec<serde_json::Value> = webhook
+        .deliveries()
+        .iter()
+        .map(|delivery| serde_json::from_str(&delivery.body).unwrap())
+        .collect();
+    assert_eq!(
+        bodies,
+        [
+            serde_json::json!({ "text": "*LAD @ SF* LAD scored 2 runs: LAD 2, SF 1 (Top 5)" }),
+            serde_json::json!({ "content": "**LAD @ SF** LAD scored 2 runs: LAD 2, SF 1 (Top 5)" }),
+            serde_json::json!({ "text": "LAD 2-1 SF, inning 5" }),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn unlisted_event_types_are_not_sent() {
+    let webhook = MockWebhook::new().start().await;
+    let sink = sink(
+        &webhook,
+        WebhookConfig {
+            events: vec!["final".to_string()],
+            ..Default::default()
+        },
+    );
+
+    sink.send(&run_scored()).await.unwrap();
+
+    assert!(webhook.deliveries().is_empty());
+}
+
+#[tokio::test]
+async fn server_errors_and_rate_limits_are_retried() {
+    let webhook = MockWebhook::new().with_statuses(&[503, 429]).start().await;
+    let sink = sink(&webhook, WebhookConfig::default());
+
+    let started = Instant::now();
+    sink.send(&run_scored()).await.unwrap();
+
+    // Backing off 0.5s, then 1s.
+    assert_eq!(webhook.deliveries().len(), 3);
+    assert!(started.elapsed() >= Duration::from_millis(1500));
+}
+
+#[tokio::test]
+async fn gives_up_after_the_configured_retries() {
+    let webhook = MockWebhook::new()
+        .with_statuses(&[500, 500, 500])
+        .start()
+        .await;
+    let sink = sink(
+        &webhook,
+        WebhookConfig {
+            retries: 1,
+            ..Default::default()
+        },
+    );
+
+    let err = sink.send(&run_scored()).await.unwrap_err();
+
+    assert!(matches!(err, Error::HttpStatus { status, .. } if status.as_u16() == 500));
+    assert_eq!(webhook.deliveries().len(), 2);
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let webhook = MockWebhook::new().with_statuses(&[400]).start().await;
+
+    let err = sink(&webhook, WebhookConfig::default())
+        .send(&run_scored())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::HttpStatus { status, .. } if status.as_u16() == 400));
+    assert_eq!(webhook.deliveries().len(), 1);
+}
+
+#[tokio::test]
+async fn unresponsive_endpoint_times_out() {
+    let webhook = MockWebhook::new().silent().start().await;
+    let sink = sink(
+        &webhook,
+        WebhookConfig {
+            timeout: 1,
+            retries: 0,
+            ..Default::default()
+        },
+    );
+
+    let result = tokio::time::timeout(Duration::from_secs(5), sink.send(&run_scored())).await;
+
+    assert!(matches!(result, Ok(Err(Error::Network { .. }))));
+}
+
+#[tokio::test]
+async fn unresponsive_endpoint_does_not_hold_up_dispatch() {
+    let silent = MockWebhook::new().silent().start().await;
+    let webhook = MockWebhook::new().start().await;
+    let config = WebhookConfig {
+        timeout: 1,
+        retries: 0,
+        ..Default::default()
+    };
+    let dispatcher = Dispatcher::new(vec![
+        Box::new(sink(&silent, config.clone())),
+        Box::new(sink(&webhook, config)),
+    ]);
+
+    let started = Instant::now();
+    dispatcher.dispatch(&run_scored());
+    dispatcher.dispatch(&run_scored());
+    assert!(started.elapsed() < Duration::from_millis(100));
+
+    dispatcher.finish().await;
+    assert_eq!(webhook.deliveries().len(), 2);
+}
+
+#[test]
+fn missing_or_malformed_url_is_a_config_error() {
+    for url in ["", "hooks.example.com/abc", "ftp://example.com/hook"] {
+        let result = WebhookSink::new(WebhookConfig {
+            url: url.to_string(),
+            ..Default::default()
+        });
+
+        assert!(matches!(result, Err(Error::Config(_))), "{:?}", url);
+    }
+}